@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use bevy_tween::{
+    bevy_time_runner::TimeRunnerEnded,
     combinator::AnimationBuilder,
     prelude::AnimationBuilderExt,
     tween::{IntoTarget, TargetComponent},
@@ -15,6 +16,11 @@ pub trait AutoTween: Component + Sized {
             .add_observer(Self::remove_observer);
     }
 
+    // for one-shot tweens whose presence means "still animating"
+    fn remove_finished_plugin(app: &mut App) {
+        app.add_systems(PostUpdate, Self::remove_finished_system);
+    }
+
     fn insert_observer(
         trigger: Trigger<OnInsert, Self>,
         mut commands: Commands,
@@ -44,4 +50,16 @@ pub trait AutoTween: Component + Sized {
             }
         }
     }
+
+    fn remove_finished_system(
+        mut commands: Commands,
+        mut ended: EventReader<TimeRunnerEnded>,
+        holders: Query<&Parent, With<Self::Holder>>,
+    ) {
+        for ended in ended.read() {
+            if let Ok(parent) = holders.get(ended.time_runner) {
+                commands.entity(parent.get()).remove::<Self>();
+            }
+        }
+    }
 }
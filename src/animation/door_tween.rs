@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_tween::{interpolate::translation, prelude::EaseKind};
+
+use super::auto_tween_trait::AutoTween;
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct DoorTween {
+    pub start_translation: Vec3,
+    pub end_translation: Vec3,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct DoorTweenHolder;
+
+impl AutoTween for DoorTween {
+    type Holder = DoorTweenHolder;
+
+    fn insert_tween(
+        &self,
+        animation: bevy_tween::combinator::AnimationBuilder,
+        target: bevy_tween::tween::TargetComponent,
+    ) {
+        animation.insert_tween_here(
+            Duration::from_millis(666),
+            EaseKind::QuadraticInOut,
+            target.with(translation(self.start_translation, self.end_translation)),
+        );
+    }
+}
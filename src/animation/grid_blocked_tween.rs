@@ -15,6 +15,7 @@ use super::{auto_tween_trait::AutoTween, grid_animated_transform};
 pub struct GridMoveBlockedTween {
     pub start_position: GridPosition,
     pub blocked_position: GridPosition,
+    pub bump_fraction: f32,
     pub start_rotation: Quat,
     pub end_rotation: Quat,
 }
@@ -33,7 +34,8 @@ impl AutoTween for GridMoveBlockedTween {
         let start_translation = Vec3::from(self.start_position);
         let blocked_translation = Vec3::from(self.blocked_position);
         let distance = start_translation.distance(blocked_translation);
-        let bump_position = start_translation.move_towards(blocked_translation, distance / 3.);
+        let bump_position =
+            start_translation.move_towards(blocked_translation, distance * self.bump_fraction);
 
         animation.insert(sequence((
             tween(
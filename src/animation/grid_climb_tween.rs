@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_tween::{
+    combinator::{sequence, tween},
+    interpolate::rotation,
+    prelude::EaseKind,
+};
+
+use crate::grid::{GridPosition, VerticalDirection};
+
+use super::{auto_tween_trait::AutoTween, grid_animated_transform};
+
+#[derive(Component, Clone, Debug)]
+pub struct GridClimbTween {
+    pub start_translation: Vec3,
+    pub end_position: GridPosition,
+    pub vertical: VerticalDirection,
+    pub start_rotation: Quat,
+    pub end_rotation: Quat,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct GridClimbTweenHolder;
+
+impl AutoTween for GridClimbTween {
+    type Holder = GridClimbTweenHolder;
+
+    fn insert_tween(
+        &self,
+        animation: bevy_tween::combinator::AnimationBuilder,
+        target: bevy_tween::tween::TargetComponent,
+    ) {
+        let start_translation = self.start_translation;
+        let end_translation = Vec3::from(self.end_position);
+
+        // climbing up rises before stepping over, climbing down steps over before descending
+        let corner_translation = match self.vertical {
+            VerticalDirection::Up => start_translation.with_y(end_translation.y),
+            VerticalDirection::Down => end_translation.with_y(start_translation.y),
+        };
+
+        animation.insert(sequence((
+            tween(
+                Duration::from_millis(444),
+                EaseKind::SineInOut,
+                (
+                    target.with(grid_animated_transform(
+                        start_translation,
+                        corner_translation,
+                    )),
+                    target.with(rotation(self.start_rotation, self.end_rotation)),
+                ),
+            ),
+            tween(
+                Duration::from_millis(222),
+                EaseKind::SineInOut,
+                target.with(grid_animated_transform(corner_translation, end_translation)),
+            ),
+        )));
+    }
+}
@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_tween::{
+    combinator::{sequence, tween},
+    interpolate::rotation,
+    prelude::EaseKind,
+};
+
+use crate::grid::GridPosition;
+
+use super::{auto_tween_trait::AutoTween, grid_animated_transform};
+
+const LEDGE_STEP_DURATION: Duration = Duration::from_millis(333);
+const FALL_LEVEL_DURATION: Duration = Duration::from_millis(222);
+
+pub fn grid_fall_duration(distance: u32) -> Duration {
+    LEDGE_STEP_DURATION + FALL_LEVEL_DURATION.mul_f32((distance as f32).sqrt())
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct GridFallTween {
+    pub start_translation: Vec3,
+    pub ledge_position: GridPosition,
+    pub end_position: GridPosition,
+    pub start_rotation: Quat,
+    pub end_rotation: Quat,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct GridFallTweenHolder;
+
+impl AutoTween for GridFallTween {
+    type Holder = GridFallTweenHolder;
+
+    fn insert_tween(
+        &self,
+        animation: bevy_tween::combinator::AnimationBuilder,
+        target: bevy_tween::tween::TargetComponent,
+    ) {
+        let distance = (self.ledge_position.y - self.end_position.y).max(0) as u32;
+
+        animation.insert(sequence((
+            tween(
+                LEDGE_STEP_DURATION,
+                EaseKind::Linear,
+                (
+                    target.with(grid_animated_transform(
+                        self.start_translation,
+                        self.ledge_position.into(),
+                    )),
+                    target.with(rotation(self.start_rotation, self.end_rotation)),
+                ),
+            ),
+            tween(
+                grid_fall_duration(distance) - LEDGE_STEP_DURATION,
+                EaseKind::QuadraticIn,
+                target.with(grid_animated_transform(
+                    self.ledge_position.into(),
+                    self.end_position.into(),
+                )),
+            ),
+        )));
+    }
+}
@@ -1,15 +1,16 @@
-use std::time::Duration;
-
 use bevy::prelude::*;
 use bevy_tween::{interpolate::rotation, prelude::EaseKind};
 
-use crate::{animation::grid_animated::grid_animated_transform, grid::GridPosition};
+use crate::{
+    animation::grid_animated::grid_animated_transform, config::GRID_MOVE_DURATION,
+    grid::GridPosition,
+};
 
 use super::auto_tween_trait::AutoTween;
 
 #[derive(Component, Clone, Debug, Default)]
 pub struct GridMoveTween {
-    pub start_position: GridPosition,
+    pub start_translation: Vec3,
     pub end_position: GridPosition,
     pub start_rotation: Quat,
     pub end_rotation: Quat,
@@ -27,11 +28,11 @@ impl AutoTween for GridMoveTween {
         target: bevy_tween::tween::TargetComponent,
     ) {
         animation.insert_tween_here(
-            Duration::from_millis(666),
+            GRID_MOVE_DURATION,
             EaseKind::ExponentialOut,
             (
                 target.with(grid_animated_transform(
-                    self.start_position.into(),
+                    self.start_translation,
                     self.end_position.into(),
                 )),
                 target.with(rotation(self.start_rotation, self.end_rotation)),
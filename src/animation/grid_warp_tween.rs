@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_tween::{
+    combinator::{sequence, tween},
+    interpolate::rotation,
+    prelude::EaseKind,
+};
+
+use crate::{config::GRID_MOVE_DURATION, grid::GridPosition};
+
+use super::{auto_tween_trait::AutoTween, grid_animated_transform};
+
+const WARP_HALF_DURATION: Duration = Duration::from_millis(333);
+const WARP_LIFT: Vec3 = Vec3::new(0.0, 0.4, 0.0);
+
+pub fn grid_warp_duration() -> Duration {
+    GRID_MOVE_DURATION + WARP_HALF_DURATION * 2
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct GridWarpTween {
+    pub start_translation: Vec3,
+    pub teleporter_position: GridPosition,
+    pub end_position: GridPosition,
+    pub start_rotation: Quat,
+    pub end_rotation: Quat,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct GridWarpTweenHolder;
+
+impl AutoTween for GridWarpTween {
+    type Holder = GridWarpTweenHolder;
+
+    fn insert_tween(
+        &self,
+        animation: bevy_tween::combinator::AnimationBuilder,
+        target: bevy_tween::tween::TargetComponent,
+    ) {
+        let teleporter = Vec3::from(self.teleporter_position);
+        let end = Vec3::from(self.end_position);
+
+        // step onto the pad, rise out of it, then sink into the destination
+        animation.insert(sequence((
+            tween(
+                GRID_MOVE_DURATION,
+                EaseKind::Linear,
+                (
+                    target.with(grid_animated_transform(self.start_translation, teleporter)),
+                    target.with(rotation(self.start_rotation, self.end_rotation)),
+                ),
+            ),
+            tween(
+                WARP_HALF_DURATION,
+                EaseKind::QuadraticIn,
+                target.with(grid_animated_transform(teleporter, teleporter + WARP_LIFT)),
+            ),
+            tween(
+                WARP_HALF_DURATION,
+                EaseKind::QuadraticOut,
+                target.with(grid_animated_transform(end + WARP_LIFT, end)),
+            ),
+        )));
+    }
+}
@@ -8,8 +8,12 @@ use bevy_tween::{
 
 use super::auto_tween_trait::AutoTween;
 
-#[derive(Component, Clone, Debug, Default)]
-pub struct ItemBobTween;
+#[derive(Component, Clone, Debug)]
+pub struct ItemBobTween {
+    pub start: Vec3,
+    pub end: Vec3,
+    pub duration: Duration,
+}
 
 #[derive(Component, Clone, Debug, Default)]
 pub struct ItemBobTweenHolder;
@@ -26,12 +30,9 @@ impl AutoTween for ItemBobTween {
             .repeat(Repeat::Infinitely)
             .repeat_style(RepeatStyle::PingPong)
             .insert_tween_here(
-                Duration::from_millis(2000),
+                self.duration,
                 EaseKind::SineInOut,
-                target.with(translation(
-                    Vec3::new(0.25, -0.16, -0.4),
-                    Vec3::new(0.25, -0.18, -0.4),
-                )),
+                target.with(translation(self.start, self.end)),
             );
     }
 }
@@ -2,15 +2,25 @@ use auto_tween_trait::AutoTween;
 use bevy::prelude::*;
 
 mod auto_tween_trait;
+mod door_tween;
 mod grid_animated;
 mod grid_blocked_tween;
+mod grid_climb_tween;
+mod grid_fall_tween;
 mod grid_move_tween;
+mod grid_warp_tween;
 mod item_bob_tween;
+mod sword_swing_tween;
 
+pub use door_tween::DoorTween;
 pub use grid_animated::{grid_animated_transform, GridAnimated};
 pub use grid_blocked_tween::GridMoveBlockedTween;
-pub use grid_move_tween::GridMoveTween;
+pub use grid_climb_tween::GridClimbTween;
+pub use grid_fall_tween::GridFallTween;
+pub use grid_move_tween::{GridMoveTween, GridMoveTweenHolder};
+pub use grid_warp_tween::GridWarpTween;
 pub use item_bob_tween::ItemBobTween;
+pub use sword_swing_tween::SwordSwingTween;
 
 pub fn plugin(app: &mut App) {
     app.add_plugins((
@@ -18,5 +28,17 @@ pub fn plugin(app: &mut App) {
         item_bob_tween::ItemBobTween::autotween_plugin,
         grid_move_tween::GridMoveTween::autotween_plugin,
         grid_blocked_tween::GridMoveBlockedTween::autotween_plugin,
+        grid_fall_tween::GridFallTween::autotween_plugin,
+        grid_climb_tween::GridClimbTween::autotween_plugin,
+        grid_warp_tween::GridWarpTween::autotween_plugin,
+        door_tween::DoorTween::autotween_plugin,
+        sword_swing_tween::SwordSwingTween::autotween_plugin,
+    ))
+    .add_plugins((
+        grid_move_tween::GridMoveTween::remove_finished_plugin,
+        grid_blocked_tween::GridMoveBlockedTween::remove_finished_plugin,
+        grid_fall_tween::GridFallTween::remove_finished_plugin,
+        grid_climb_tween::GridClimbTween::remove_finished_plugin,
+        grid_warp_tween::GridWarpTween::remove_finished_plugin,
     ));
 }
@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_tween::{
+    combinator::{sequence, tween},
+    interpolate::rotation,
+    prelude::EaseKind,
+};
+
+use crate::config::SWORD_STRIKE_DELAY;
+
+use super::auto_tween_trait::AutoTween;
+
+const SWORD_RECOVER_DURATION: Duration = Duration::from_millis(250);
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct SwordSwingTween {
+    pub rest_rotation: Quat,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct SwordSwingTweenHolder;
+
+impl AutoTween for SwordSwingTween {
+    type Holder = SwordSwingTweenHolder;
+
+    fn insert_tween(
+        &self,
+        animation: bevy_tween::combinator::AnimationBuilder,
+        target: bevy_tween::tween::TargetComponent,
+    ) {
+        // slash across the view, then ease back to where the sword rests
+        let swung_rotation =
+            Quat::from_rotation_z(0.8) * Quat::from_rotation_y(0.6) * self.rest_rotation;
+
+        animation.insert(sequence((
+            tween(
+                SWORD_STRIKE_DELAY,
+                EaseKind::QuadraticIn,
+                target.with(rotation(self.rest_rotation, swung_rotation)),
+            ),
+            tween(
+                SWORD_RECOVER_DURATION,
+                EaseKind::QuadraticOut,
+                target.with(rotation(swung_rotation, self.rest_rotation)),
+            ),
+        )));
+    }
+}
@@ -1,9 +1,10 @@
 use std::ops::Deref;
 
 use crate::{
-    blocks::{block_layer::BlockSource, block_traits::Block},
+    animation::GridAnimated,
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
     camera::PlayerCamera,
-    grid::{Direction, GridCollides, GridDirection},
+    grid::{Direction, GridCollides, GridDirection, GridSystemSet},
     GameStates,
 };
 use bevy::prelude::*;
@@ -12,10 +13,15 @@ use bevy_ecs_ldtk::prelude::LdtkFields;
 use super::mesh::BillboardMesh;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_observer(billboard_setup_observer).add_systems(
-        Update,
-        billboard_movement_system.run_if(in_state(GameStates::Playing)),
-    );
+    app.add_observer(billboard_setup_observer)
+        .add_systems(
+            Update,
+            billboard_movement_system.run_if(in_state(GameStates::Playing)),
+        )
+        .add_systems(
+            PostUpdate,
+            billboard_sprite_rotation_system.in_set(GridSystemSet::Cleanup),
+        );
 }
 
 #[derive(Clone, Debug)]
@@ -27,23 +33,25 @@ pub struct BillboardBlock {
 #[derive(Component, Clone, Debug, Default)]
 pub struct BillboardBlockMarker;
 
-impl From<BlockSource> for BillboardBlock {
-    fn from(value: BlockSource) -> Self {
+impl TryFrom<BlockSource> for BillboardBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
         match value {
             BlockSource::Entity(entity_instance) => {
-                let direction = Direction::from(&entity_instance);
+                let direction = Direction::try_from(&entity_instance)?;
 
                 let face_camera = entity_instance
                     .get_bool_field("face_camera")
                     .cloned()
                     .unwrap_or_default();
 
-                Self {
+                Ok(Self {
                     direction,
                     face_camera,
-                }
+                })
             }
-            BlockSource::Tile(_tile_instance) => todo!(),
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
         }
     }
 }
@@ -77,7 +85,14 @@ pub struct BillboardFaceCamera;
 fn billboard_setup_observer(
     trigger: Trigger<OnInsert, BillboardFaceCamera>,
     player_camera: Query<&GridDirection, With<PlayerCamera>>,
-    mut billboards: Query<&mut GridDirection, (Without<PlayerCamera>, With<BillboardFaceCamera>)>,
+    mut billboards: Query<
+        &mut GridDirection,
+        (
+            Without<PlayerCamera>,
+            With<BillboardFaceCamera>,
+            Without<GridAnimated>,
+        ),
+    >,
 ) {
     let Ok(camera_direction) = player_camera.get_single() else {
         return;
@@ -91,7 +106,14 @@ fn billboard_setup_observer(
 
 fn billboard_movement_system(
     player_camera: Query<&GridDirection, (With<PlayerCamera>, Changed<GridDirection>)>,
-    mut billboards: Query<&mut GridDirection, (Without<PlayerCamera>, With<BillboardFaceCamera>)>,
+    mut billboards: Query<
+        &mut GridDirection,
+        (
+            Without<PlayerCamera>,
+            With<BillboardFaceCamera>,
+            Without<GridAnimated>,
+        ),
+    >,
 ) {
     if let Ok(camera_direction) = player_camera.get_single() {
         for mut billboard_direction in &mut billboards {
@@ -99,3 +121,16 @@ fn billboard_movement_system(
         }
     }
 }
+
+// a billboard that moves on the grid keeps its own facing for ladders and the like, so only its
+// sprite is turned towards the camera, once the movement tweens have set its rotation
+fn billboard_sprite_rotation_system(
+    player_camera: Query<&GridDirection, With<PlayerCamera>>,
+    mut billboards: Query<&mut Transform, (With<BillboardFaceCamera>, With<GridAnimated>)>,
+) {
+    if let Ok(camera_direction) = player_camera.get_single() {
+        for mut transform in &mut billboards {
+            transform.rotation = (*camera_direction).into();
+        }
+    }
+}
@@ -3,7 +3,8 @@ use bevy::prelude::*;
 mod block;
 mod mesh;
 
-pub use block::BillboardBlock;
+pub use block::{BillboardBlock, BillboardFaceCamera};
+pub use mesh::BillboardMesh;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins(block::plugin);
@@ -2,44 +2,56 @@ use bevy::{prelude::*, render::view::RenderLayers};
 
 use crate::grid::GridPosition;
 
-use super::{block_layer::BlockSource, block_traits::Block, BlockLayer, BlockMeshMap};
+use super::{
+    block_layer::BlockSource,
+    block_report::{BlockOrigin, BlockProblem},
+    block_traits::Block,
+    BlockLayer, BlockMeshMap,
+};
 
 pub struct BlockInstance<B: Block> {
     pub tile_xy: IVec2,
     pub sprite_xy: IVec2,
+    pub iid: Option<String>,
     pub block: B,
 }
 
 #[derive(Component, Clone, Debug, Default)]
 pub struct BlockSpawnedFromLdtk;
 
-impl<B: Block> From<BlockSource> for BlockInstance<B> {
-    fn from(value: BlockSource) -> Self {
-        let (tile_xy, sprite_xy) = match value {
+#[derive(Component, Deref, Clone, Debug)]
+pub struct LdtkIid(pub String);
+
+impl<B: Block> TryFrom<BlockSource> for BlockInstance<B> {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        let (tile_xy, sprite_xy, iid) = match value {
             BlockSource::Entity(ref entity_instance) => {
                 let tile_xy = entity_instance.px;
                 let sprite_xy = entity_instance
                     .tile
                     .map(|t| IVec2::new(t.x, t.y))
-                    .expect("ENTITY MISSING A SPRITESHEET TILE");
+                    .ok_or(BlockProblem::MissingTile)?;
 
-                (tile_xy, sprite_xy)
+                (tile_xy, sprite_xy, Some(entity_instance.iid.clone()))
             }
             BlockSource::Tile(ref tile_instance) => {
                 let tile_xy = tile_instance.px;
                 let sprite_xy = tile_instance.src;
 
-                (tile_xy, sprite_xy)
+                (tile_xy, sprite_xy, None)
             }
         };
 
-        let block = B::from(value);
+        let block = B::try_from(value)?;
 
-        Self {
+        Ok(Self {
             tile_xy,
             sprite_xy,
+            iid,
             block,
-        }
+        })
     }
 }
 
@@ -50,8 +62,12 @@ impl<B: Block> BlockInstance<B> {
         mut commands: Commands,
         mesh_map: &BlockMeshMap<B>,
         material: &Handle<StandardMaterial>,
-    ) {
-        let entity = commands.spawn((
+    ) -> Result<(), BlockProblem> {
+        let mesh = mesh_map
+            .get(&(self.sprite_xy.x, self.sprite_xy.y))
+            .ok_or(BlockProblem::MissingMesh(self.sprite_xy))?;
+
+        let mut entity = commands.spawn((
             B::MarkerType::default(),
             BlockSpawnedFromLdtk,
             RenderLayers::layer(1),
@@ -62,10 +78,18 @@ impl<B: Block> BlockInstance<B> {
             )),
         ));
 
-        let mesh = mesh_map
-            .get(&(self.sprite_xy.x, self.sprite_xy.y))
-            .expect("MISSING MESH FOR SPRITE COORDS");
+        if let Some(iid) = &self.iid {
+            entity.insert((
+                LdtkIid(iid.clone()),
+                BlockOrigin {
+                    level: block_layer.level.clone(),
+                    layer: block_layer.layer.clone(),
+                },
+            ));
+        }
 
         self.block.specialize(entity, mesh, material);
+
+        Ok(())
     }
 }
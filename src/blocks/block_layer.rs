@@ -7,14 +7,18 @@ use image::DynamicImage;
 
 use super::{
     block_instances::BlockInstance,
+    block_report::{BlockDiagnostic, BlockProblem},
     block_traits::{Block, BlockMeshHandle},
     BlockMesh,
 };
 
 pub struct BlockLayer<B: Block> {
+    pub level: String,
+    pub layer: String,
     pub sprite_size: IVec2,
     pub offset: IVec3,
     pub blocks: Vec<BlockInstance<B>>,
+    pub diagnostics: Vec<BlockDiagnostic>,
 }
 
 pub enum BlockSource {
@@ -36,27 +40,49 @@ impl<B: Block> BlockLayer<B> {
         let sprite_size = IVec2::new(layer.c_wid, layer.c_hei);
         let offset = IVec3::new(*level.world_x(), *level.world_depth(), *level.world_y());
 
-        let blocks = match layer.layer_instance_type {
-            ldtk::Type::IntGrid => Self::collect_tile_block_instances(&layer.auto_layer_tiles),
-            ldtk::Type::Tiles => Self::collect_tile_block_instances(&layer.grid_tiles),
-            ldtk::Type::AutoLayer => Self::collect_tile_block_instances(&layer.auto_layer_tiles),
-            ldtk::Type::Entities => Self::collect_entity_block_instances(&layer.entity_instances),
-        };
-
-        Self {
+        let mut block_layer = Self {
+            level: level.identifier().clone(),
+            layer: layer.identifier.clone(),
             sprite_size,
             offset,
-            blocks,
+            blocks: vec![],
+            diagnostics: vec![],
+        };
+
+        match layer.layer_instance_type {
+            ldtk::Type::IntGrid => {
+                block_layer.collect_tile_block_instances(&layer.auto_layer_tiles)
+            }
+            ldtk::Type::Tiles => block_layer.collect_tile_block_instances(&layer.grid_tiles),
+            ldtk::Type::AutoLayer => {
+                block_layer.collect_tile_block_instances(&layer.auto_layer_tiles)
+            }
+            ldtk::Type::Entities => {
+                block_layer.collect_entity_block_instances(&layer.entity_instances)
+            }
+        };
+
+        block_layer
+    }
+
+    pub fn diagnostic(&self, iid: Option<String>, problem: BlockProblem) -> BlockDiagnostic {
+        BlockDiagnostic {
+            level: self.level.clone(),
+            layer: self.layer.clone(),
+            iid,
+            problem,
         }
     }
 
+    // returns the problems found while building along with any blocks skipped while spawning
     pub fn spawn(
         &self,
         mut commands: Commands,
         meshes: &mut ResMut<Assets<Mesh>>,
         tileset: &DynamicImage,
         material: &Handle<StandardMaterial>,
-    ) {
+    ) -> Vec<BlockDiagnostic> {
+        let mut diagnostics = self.diagnostics.clone();
         let mut mesh_map = BlockMeshMap::<B>::default();
         let width_in_tiles = tileset.width() as i32 / 16;
         let height_in_tiles = tileset.height() as i32 / 16;
@@ -74,29 +100,38 @@ impl<B: Block> BlockLayer<B> {
         }
 
         for block in &self.blocks {
-            block.spawn(self, commands.reborrow(), &mesh_map, material);
+            if let Err(problem) = block.spawn(self, commands.reborrow(), &mesh_map, material) {
+                diagnostics.push(self.diagnostic(block.iid.clone(), problem));
+            }
         }
-    }
 
-    fn collect_tile_block_instances(tiles: &[TileInstance]) -> Vec<BlockInstance<B>> {
-        let mut instances = vec![];
+        // kept around so the registered cleanup can free these meshes on the next reload
+        commands.spawn(mesh_map);
+
+        diagnostics
+    }
 
+    fn collect_tile_block_instances(&mut self, tiles: &[TileInstance]) {
         for tile in tiles.iter() {
-            instances.push(BlockInstance::<B>::from(BlockSource::Tile(tile.clone())));
+            match BlockInstance::<B>::try_from(BlockSource::Tile(tile.clone())) {
+                Ok(instance) => self.blocks.push(instance),
+                Err(problem) => {
+                    let diagnostic = self.diagnostic(None, problem);
+                    self.diagnostics.push(diagnostic);
+                }
+            }
         }
-
-        instances
     }
 
-    fn collect_entity_block_instances(entities: &[EntityInstance]) -> Vec<BlockInstance<B>> {
-        let mut instances = vec![];
-
+    fn collect_entity_block_instances(&mut self, entities: &[EntityInstance]) {
         for entity in entities.iter() {
-            instances.push(BlockInstance::<B>::from(BlockSource::Entity(
-                entity.clone(),
-            )));
+            match BlockInstance::<B>::try_from(BlockSource::Entity(entity.clone())) {
+                Ok(instance) => self.blocks.push(instance),
+                Err(problem) => {
+                    let diagnostic = self.diagnostic(Some(entity.iid.clone()), problem);
+                    self.diagnostics.push(diagnostic);
+                }
+            }
         }
-
-        instances
     }
 }
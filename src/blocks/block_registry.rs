@@ -0,0 +1,170 @@
+use std::ops::Deref;
+
+use bevy::{prelude::*, utils::HashMap};
+use bevy_ecs_ldtk::ldtk::{loaded_level::LoadedLevel, LayerInstance};
+use image::DynamicImage;
+
+use super::{block_report::BlockDiagnostic, block_traits::Block, BlockLayer, BlockMeshMap};
+
+pub type BlockSpawner = fn(
+    &LoadedLevel,
+    &LayerInstance,
+    Commands,
+    &mut ResMut<Assets<Mesh>>,
+    &DynamicImage,
+    &Handle<StandardMaterial>,
+) -> Vec<BlockDiagnostic>;
+
+pub type BlockCleanup = fn(&mut World);
+
+#[derive(Clone, Copy)]
+pub struct BlockRegistration {
+    pub spawn: BlockSpawner,
+    pub cleanup: BlockCleanup,
+}
+
+impl BlockRegistration {
+    fn new<B: Block + 'static>() -> Self {
+        Self {
+            spawn: spawn_block_layer::<B>,
+            cleanup: cleanup_block_meshes::<B>,
+        }
+    }
+}
+
+// layers are keyed by their identifier prefix, the part before the first `_`,
+// entities by their identifier so they spawn as their own type in any entity layer
+#[derive(Resource, Default)]
+pub struct BlockRegistry {
+    layers: HashMap<&'static str, BlockRegistration>,
+    entities: HashMap<&'static str, BlockRegistration>,
+}
+
+impl BlockRegistry {
+    pub fn get(&self, prefix: &str) -> Option<&BlockRegistration> {
+        self.layers.get(prefix)
+    }
+
+    pub fn get_entity(&self, identifier: &str) -> Option<&BlockRegistration> {
+        self.entities.get(identifier)
+    }
+
+    pub fn registrations(&self) -> impl Iterator<Item = &BlockRegistration> {
+        self.layers.values().chain(self.entities.values())
+    }
+}
+
+pub trait RegisterBlockExt {
+    fn register_block<B: Block + 'static>(&mut self, prefix: &'static str) -> &mut Self;
+    fn register_entity_block<B: Block + 'static>(&mut self, identifier: &'static str) -> &mut Self;
+}
+
+impl RegisterBlockExt for App {
+    fn register_block<B: Block + 'static>(&mut self, prefix: &'static str) -> &mut Self {
+        let registration = BlockRegistration::new::<B>();
+
+        let mut registry = self
+            .init_resource::<BlockRegistry>()
+            .world_mut()
+            .resource_mut::<BlockRegistry>();
+
+        if registry.layers.insert(prefix, registration).is_some() {
+            log::warn!("BLOCK LAYER PREFIX REGISTERED TWICE: {prefix}");
+        }
+
+        self
+    }
+
+    fn register_entity_block<B: Block + 'static>(&mut self, identifier: &'static str) -> &mut Self {
+        let registration = BlockRegistration::new::<B>();
+
+        let mut registry = self
+            .init_resource::<BlockRegistry>()
+            .world_mut()
+            .resource_mut::<BlockRegistry>();
+
+        if registry.entities.insert(identifier, registration).is_some() {
+            log::warn!("BLOCK ENTITY IDENTIFIER REGISTERED TWICE: {identifier}");
+        }
+
+        self
+    }
+}
+
+// splits the entities that have their own registered block type out of a layer,
+// returning one layer per registration and the leftover layer if anything was taken
+pub fn split_entity_layers<'a>(
+    registry: &'a BlockRegistry,
+    layer: &LayerInstance,
+) -> (
+    Vec<(&'a BlockRegistration, LayerInstance)>,
+    Option<LayerInstance>,
+) {
+    let mut entity_layers: HashMap<&'static str, (&BlockRegistration, LayerInstance)> =
+        HashMap::default();
+    let mut remaining = vec![];
+
+    for entity in &layer.entity_instances {
+        let Some((identifier, registration)) =
+            registry.entities.get_key_value(entity.identifier.as_str())
+        else {
+            remaining.push(entity.clone());
+            continue;
+        };
+
+        entity_layers
+            .entry(*identifier)
+            .or_insert_with(|| {
+                (
+                    registration,
+                    LayerInstance {
+                        entity_instances: vec![],
+                        ..layer.clone()
+                    },
+                )
+            })
+            .1
+            .entity_instances
+            .push(entity.clone());
+    }
+
+    if entity_layers.is_empty() {
+        return (vec![], None);
+    }
+
+    let remaining = LayerInstance {
+        entity_instances: remaining,
+        ..layer.clone()
+    };
+
+    (entity_layers.into_values().collect(), Some(remaining))
+}
+
+fn spawn_block_layer<B: Block + 'static>(
+    level: &LoadedLevel,
+    layer: &LayerInstance,
+    commands: Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    tileset: &DynamicImage,
+    material: &Handle<StandardMaterial>,
+) -> Vec<BlockDiagnostic> {
+    BlockLayer::<B>::build(level, layer).spawn(commands, meshes, tileset, material)
+}
+
+fn cleanup_block_meshes<B: Block + 'static>(world: &mut World) {
+    let mesh_maps = world
+        .query_filtered::<Entity, With<BlockMeshMap<B>>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+
+    for entity in mesh_maps {
+        if let Some(mesh_map) = world.entity_mut(entity).take::<BlockMeshMap<B>>() {
+            let mut meshes = world.resource_mut::<Assets<Mesh>>();
+            for mesh in mesh_map.values() {
+                meshes.remove(mesh.deref());
+            }
+        }
+
+        world.entity_mut(entity).despawn_recursive();
+    }
+}
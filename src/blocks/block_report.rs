@@ -0,0 +1,65 @@
+use std::fmt;
+
+use bevy::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BlockProblem {
+    UnknownLayer,
+    UnsupportedSource(&'static str),
+    MissingTile,
+    MissingMesh(IVec2),
+    MissingField(&'static str),
+    InvalidEnum { field: &'static str, value: String },
+    DanglingReference { field: &'static str, iid: String },
+}
+
+impl fmt::Display for BlockProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlockProblem::UnknownLayer => write!(f, "no block type for this layer"),
+            BlockProblem::UnsupportedSource(source) => {
+                write!(f, "block type can't be placed as a {source}")
+            }
+            BlockProblem::MissingTile => write!(f, "entity missing a spritesheet tile"),
+            BlockProblem::MissingMesh(sprite_xy) => {
+                write!(f, "no mesh for sprite at {}, {}", sprite_xy.x, sprite_xy.y)
+            }
+            BlockProblem::MissingField(field) => write!(f, "missing field `{field}`"),
+            BlockProblem::InvalidEnum { field, value } => {
+                write!(f, "invalid value `{value}` for `{field}`")
+            }
+            BlockProblem::DanglingReference { field, iid } => {
+                write!(f, "`{field}` points at missing block {iid}")
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BlockDiagnostic {
+    pub level: String,
+    pub layer: String,
+    pub iid: Option<String>,
+    pub problem: BlockProblem,
+}
+
+impl fmt::Display for BlockDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} / {}", self.level, self.layer)?;
+        if let Some(iid) = &self.iid {
+            write!(f, " / {iid}")?;
+        }
+        write!(f, ": {}", self.problem)
+    }
+}
+
+// where an entity block came from, for problems only found after spawning
+#[derive(Component, Clone, Debug)]
+pub struct BlockOrigin {
+    pub level: String,
+    pub layer: String,
+}
+
+// everything skipped by the last level load, rebuilt on every hot reload
+#[derive(Resource, Default, Debug, Deref, DerefMut)]
+pub struct LevelReport(pub Vec<BlockDiagnostic>);
@@ -6,11 +6,11 @@ use bevy::{
     render::mesh::{Indices, PrimitiveTopology},
 };
 
-use super::block_layer::BlockSource;
+use super::{block_layer::BlockSource, block_report::BlockProblem};
 
 pub type BlockMeshHandle<T> = <<T as Block>::BlockMeshType as BlockMesh>::Handle;
 
-pub trait Block: From<BlockSource> {
+pub trait Block: TryFrom<BlockSource, Error = BlockProblem> {
     type MarkerType: Default + Component;
     type BlockMeshType: BlockMesh;
 
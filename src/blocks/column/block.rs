@@ -3,7 +3,7 @@ use bevy::prelude::*;
 use std::ops::Deref;
 
 use crate::{
-    blocks::{block_layer::BlockSource, block_traits::Block},
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
     grid::GridCollides,
 };
 
@@ -15,11 +15,13 @@ pub struct ColumnBlock;
 #[derive(Component, Clone, Debug, Default)]
 pub struct ColumnBlockMarker;
 
-impl From<BlockSource> for ColumnBlock {
-    fn from(value: BlockSource) -> Self {
+impl TryFrom<BlockSource> for ColumnBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
         match value {
-            BlockSource::Entity(_entity_instance) => todo!(),
-            BlockSource::Tile(_tile_instance) => Self,
+            BlockSource::Entity(_entity_instance) => Err(BlockProblem::UnsupportedSource("entity")),
+            BlockSource::Tile(_tile_instance) => Ok(Self),
         }
     }
 }
@@ -0,0 +1,323 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::LdtkFields;
+use bevy_persistent::Persistent;
+
+use crate::{
+    animation::DoorTween,
+    blocks::{
+        block_layer::BlockSource, block_report::BlockProblem, block_traits::Block, LdtkIid,
+        SwitchActivate, SwitchDeactivate,
+    },
+    camera::PlayerCamera,
+    grid::{
+        Direction, GridCollides, GridDirection, GridInteract, GridMoveBlocked, GridPosition,
+        GridWorld, Interactable,
+    },
+    inventory::Inventory,
+    message::ShowMessage,
+    GameStates,
+};
+
+use super::mesh::DoorMesh;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(door_interact_observer)
+        .add_observer(door_unlocked_setup_observer)
+        .add_observer(door_toggle_observer)
+        .add_observer(locked_door_bump_observer)
+        .add_observer(door_switch_activate_observer)
+        .add_observer(door_switch_deactivate_observer)
+        .add_observer(door_set_open_observer)
+        .add_systems(
+            Update,
+            door_pending_close_system.run_if(in_state(GameStates::Playing)),
+        );
+}
+
+#[derive(Clone, Debug)]
+pub struct DoorBlock {
+    direction: Direction,
+    locked: bool,
+    key: Option<DoorKey>,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct DoorBlockMarker;
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct Door {
+    pub open: bool,
+    pub locked: bool,
+    // asked to close while the doorway was occupied
+    pub closing: bool,
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct DoorKey {
+    pub id: String,
+    pub consume: bool,
+}
+
+#[derive(Event, Default, Debug, Clone)]
+pub struct DoorToggle;
+
+#[derive(Event, Debug, Clone)]
+pub struct DoorSetOpen(pub bool);
+
+impl TryFrom<BlockSource> for DoorBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(entity_instance) => {
+                let direction = Direction::try_from(&entity_instance)?;
+
+                let locked = entity_instance
+                    .get_bool_field("locked")
+                    .cloned()
+                    .unwrap_or_default();
+
+                let consume = entity_instance
+                    .get_bool_field("consume_key")
+                    .cloned()
+                    .unwrap_or_default();
+
+                let key = entity_instance
+                    .get_string_field("key_id")
+                    .ok()
+                    .map(|id| DoorKey {
+                        id: id.clone(),
+                        consume,
+                    });
+
+                Ok(Self {
+                    direction,
+                    locked,
+                    key,
+                })
+            }
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl Block for DoorBlock {
+    type MarkerType = DoorBlockMarker;
+    type BlockMeshType = DoorMesh;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            GridDirection(self.direction),
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            GridCollides,
+            Door {
+                locked: self.locked,
+                ..default()
+            },
+            Interactable::new(if self.locked {
+                locked_prompt(self.key.as_ref())
+            } else {
+                "open door".to_string()
+            }),
+        ));
+
+        if let Some(key) = &self.key {
+            entity.insert(key.clone());
+        }
+    }
+}
+
+fn locked_prompt(key: Option<&DoorKey>) -> String {
+    match key {
+        Some(key) => format!("locked, needs {}", key.id),
+        None => "locked".to_string(),
+    }
+}
+
+fn door_unlocked_setup_observer(
+    trigger: Trigger<OnAdd, Door>,
+    mut commands: Commands,
+    mut doors: Query<(&mut Door, &LdtkIid)>,
+    inventory: Res<Persistent<Inventory>>,
+) {
+    let Ok((mut door, iid)) = doors.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    if door.locked && inventory.unlocked.contains(&iid.0) {
+        door.locked = false;
+        commands
+            .entity(trigger.entity())
+            .insert(Interactable::new("open door"));
+    }
+}
+
+fn door_interact_observer(
+    trigger: Trigger<GridInteract>,
+    mut commands: Commands,
+    doors: Query<(), With<Door>>,
+) {
+    if doors.contains(trigger.entity()) {
+        commands.trigger_targets(DoorToggle, trigger.entity());
+    }
+}
+
+fn door_toggle_observer(
+    trigger: Trigger<DoorToggle>,
+    mut commands: Commands,
+    mut doors: Query<(&mut Door, &GridPosition, Option<&DoorKey>, Option<&LdtkIid>)>,
+    player: Query<Entity, With<PlayerCamera>>,
+    mut inventory: ResMut<Persistent<Inventory>>,
+) {
+    let Ok((mut door, position, key, iid)) = doors.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    if door.locked {
+        let Some(key) = key.filter(|key| inventory.count(&key.id) > 0) else {
+            // bump against the door, the bump observer explains what's missing
+            if let Ok(player) = player.get_single() {
+                commands.trigger_targets(GridMoveBlocked(*position), player);
+            }
+            return;
+        };
+
+        if key.consume {
+            inventory.remove(&key.id, 1);
+        }
+        if let Some(iid) = iid {
+            inventory.unlocked.push(iid.0.clone());
+        }
+
+        door.locked = false;
+        commands.trigger(ShowMessage(format!("unlocked with {}", key.id)));
+    }
+
+    commands.trigger_targets(DoorSetOpen(!door.open), trigger.entity());
+}
+
+fn locked_door_bump_observer(
+    trigger: Trigger<GridMoveBlocked>,
+    mut commands: Commands,
+    player: Query<(), With<PlayerCamera>>,
+    doors: Query<(&Door, Option<&DoorKey>)>,
+    grid_world: Res<GridWorld>,
+) {
+    if !player.contains(trigger.entity()) {
+        return;
+    }
+
+    if let Some(key) = grid_world
+        .occupants(&trigger.0)
+        .filter_map(|occupant| doors.get(occupant.entity).ok())
+        .find(|(door, _)| door.locked)
+        .map(|(_, key)| key)
+    {
+        commands.trigger(ShowMessage(locked_prompt(key)));
+    }
+}
+
+fn door_switch_activate_observer(
+    trigger: Trigger<SwitchActivate>,
+    mut commands: Commands,
+    doors: Query<(), With<Door>>,
+) {
+    if doors.contains(trigger.entity()) {
+        commands.trigger_targets(DoorSetOpen(true), trigger.entity());
+    }
+}
+
+fn door_switch_deactivate_observer(
+    trigger: Trigger<SwitchDeactivate>,
+    mut commands: Commands,
+    doors: Query<(), With<Door>>,
+) {
+    if doors.contains(trigger.entity()) {
+        commands.trigger_targets(DoorSetOpen(false), trigger.entity());
+    }
+}
+
+fn door_set_open_observer(
+    trigger: Trigger<DoorSetOpen>,
+    mut commands: Commands,
+    mut doors: Query<(&mut Door, &GridPosition, &Transform)>,
+    grid_world: Res<GridWorld>,
+) {
+    let Ok((mut door, position, transform)) = doors.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    if trigger.0 {
+        door.closing = false;
+    }
+
+    // switches don't override a lock, only the matching key does
+    if door.open == trigger.0 || (trigger.0 && door.locked) {
+        return;
+    }
+
+    let doorway_occupied = grid_world
+        .occupants(position)
+        .any(|occupant| occupant.entity != trigger.entity());
+
+    // the close is kept until the doorway clears, so a switch's state isn't lost
+    if door.open && doorway_occupied {
+        door.closing = true;
+        return;
+    }
+
+    door.open = trigger.0;
+    door.closing = false;
+
+    let closed_translation = Vec3::from(position);
+    let end_translation = if door.open {
+        closed_translation + Vec3::Y * 0.9
+    } else {
+        closed_translation
+    };
+
+    let mut entity = commands.entity(trigger.entity());
+
+    if door.open {
+        entity.remove::<GridCollides>();
+    } else {
+        entity.insert(GridCollides);
+    }
+
+    entity.insert(DoorTween {
+        start_translation: transform.translation,
+        end_translation,
+    });
+
+    if !door.locked {
+        entity.insert(Interactable::new(if door.open {
+            "close door"
+        } else {
+            "open door"
+        }));
+    }
+}
+
+fn door_pending_close_system(
+    mut commands: Commands,
+    doors: Query<(Entity, &Door, &GridPosition)>,
+    grid_world: Res<GridWorld>,
+) {
+    for (entity, door, position) in &doors {
+        let doorway_clear = grid_world
+            .occupants(position)
+            .all(|occupant| occupant.entity == entity);
+
+        if door.closing && doorway_clear {
+            commands.trigger_targets(DoorSetOpen(false), entity);
+        }
+    }
+}
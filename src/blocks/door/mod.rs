@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+mod block;
+mod mesh;
+
+pub use block::DoorBlock;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(block::plugin);
+}
@@ -0,0 +1,95 @@
+use std::{fmt::Debug, marker::PhantomData, ops::Deref};
+
+use bevy::prelude::*;
+
+use crate::{
+    blocks::{
+        block_layer::BlockSource,
+        block_report::BlockProblem,
+        block_traits::{Block, BlockMeshHandle},
+    },
+    grid::{Direction, EdgeCollider, GridDirection},
+};
+
+use super::mesh::EdgeMesh;
+
+// each edge block is the same panel, its marker picks which edge of the cell it sits on
+// relative to the direction set in the level
+pub trait EdgeKind: Component + Default + Clone + Debug {
+    fn rotate(direction: Direction) -> Direction {
+        direction
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct EdgeBlock<K: EdgeKind> {
+    direction: Direction,
+    kind: PhantomData<K>,
+}
+
+pub type MetalFenceBlock = EdgeBlock<MetalFenceBlockMarker>;
+pub type CastleWindowBlock = EdgeBlock<CastleWindowBlockMarker>;
+pub type LeftShapeBlock = EdgeBlock<LeftShapeBlockMarker>;
+pub type RightShapeBlock = EdgeBlock<RightShapeBlockMarker>;
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct MetalFenceBlockMarker;
+
+impl EdgeKind for MetalFenceBlockMarker {}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct CastleWindowBlockMarker;
+
+impl EdgeKind for CastleWindowBlockMarker {}
+
+// shapes trim along the left or right edge of the cell, as seen looking towards `direction`
+#[derive(Component, Clone, Debug, Default)]
+pub struct LeftShapeBlockMarker;
+
+impl EdgeKind for LeftShapeBlockMarker {
+    fn rotate(direction: Direction) -> Direction {
+        direction.left()
+    }
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct RightShapeBlockMarker;
+
+impl EdgeKind for RightShapeBlockMarker {
+    fn rotate(direction: Direction) -> Direction {
+        direction.right()
+    }
+}
+
+impl<K: EdgeKind> TryFrom<BlockSource> for EdgeBlock<K> {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(entity_instance) => Ok(Self {
+                direction: Direction::try_from(&entity_instance)?,
+                kind: PhantomData,
+            }),
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl<K: EdgeKind> Block for EdgeBlock<K> {
+    type MarkerType = K;
+    type BlockMeshType = EdgeMesh;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            GridDirection(K::rotate(self.direction)),
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            EdgeCollider::FRONT,
+        ));
+    }
+}
@@ -0,0 +1,71 @@
+use crate::blocks::block_traits::BlockMesh;
+use bevy::prelude::*;
+
+// a thin panel along the north edge of the cell, rotated onto other edges by `GridDirection`
+#[derive(Clone, Debug)]
+pub struct EdgeMesh;
+
+#[derive(Resource, Deref, Clone, Debug)]
+pub struct EdgeMeshHandle(Handle<Mesh>);
+
+impl From<Handle<Mesh>> for EdgeMeshHandle {
+    fn from(value: Handle<Mesh>) -> Self {
+        Self(value)
+    }
+}
+
+impl BlockMesh for EdgeMesh {
+    type Handle = EdgeMeshHandle;
+
+    fn indices() -> Vec<u32> {
+        vec![
+            0, 1, 2, 0, 2, 3, // south (+z)
+            4, 5, 6, 4, 6, 7, // north (-z)
+        ]
+    }
+
+    fn positions() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // south (+z), inner face
+            [0.5, 0.5, -0.45],
+            [-0.5, 0.5, -0.45],
+            [-0.5, -0.5, -0.45],
+            [0.5, -0.5, -0.45],
+            // north (-z), outer face
+            [-0.5, 0.5, -0.49],
+            [0.5, 0.5, -0.49],
+            [0.5, -0.5, -0.49],
+            [-0.5, -0.5, -0.49],
+        ]
+    }
+
+    fn uvs() -> std::vec::Vec<[f32; 2]> {
+        vec![
+            // south (+z)
+            [1.0, 0.0],
+            [0.0, 0.0],
+            [0.0, 1.0],
+            [1.0, 1.0],
+            // north (-z)
+            [1.0, 0.0],
+            [0.0, 0.0],
+            [0.0, 1.0],
+            [1.0, 1.0],
+        ]
+    }
+
+    fn normals() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // south (+z)
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            // north (-z)
+            [0.0, 0.0, -1.0],
+            [0.0, 0.0, -1.0],
+            [0.0, 0.0, -1.0],
+            [0.0, 0.0, -1.0],
+        ]
+    }
+}
@@ -0,0 +1,80 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::LdtkFields;
+
+use crate::{
+    blocks::{
+        billboard::{BillboardFaceCamera, BillboardMesh},
+        block_layer::BlockSource,
+        block_report::BlockProblem,
+        block_traits::Block,
+    },
+    grid::{Direction, GridDirection},
+    inventory::Pickup,
+};
+
+#[derive(Clone, Debug)]
+pub struct ItemBlock {
+    direction: Direction,
+    pickup: Pickup,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct ItemBlockMarker;
+
+impl TryFrom<BlockSource> for ItemBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(entity_instance) => {
+                let direction = Direction::try_from(&entity_instance)?;
+
+                let id = entity_instance
+                    .get_string_field("item")
+                    .map_err(|_| BlockProblem::MissingField("item"))?
+                    .clone();
+
+                let count = entity_instance
+                    .get_int_field("count")
+                    .map_or(1, |&count| count.max(1) as u32);
+
+                let consumable = entity_instance
+                    .get_bool_field("consumable")
+                    .cloned()
+                    .unwrap_or_default();
+
+                Ok(Self {
+                    direction,
+                    pickup: Pickup {
+                        id,
+                        count,
+                        consumable,
+                    },
+                })
+            }
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl Block for ItemBlock {
+    type MarkerType = ItemBlockMarker;
+    type BlockMeshType = BillboardMesh;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            GridDirection(self.direction),
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            BillboardFaceCamera,
+            self.pickup.clone(),
+        ));
+    }
+}
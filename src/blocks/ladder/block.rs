@@ -0,0 +1,50 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+
+use crate::{
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
+    grid::{Direction, GridDirection},
+};
+
+use super::mesh::LadderMesh;
+
+#[derive(Clone, Debug)]
+pub struct LadderBlock {
+    direction: Direction,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct LadderBlockMarker;
+
+impl TryFrom<BlockSource> for LadderBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(entity_instance) => {
+                let direction = Direction::try_from(&entity_instance)?;
+                Ok(Self { direction })
+            }
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl Block for LadderBlock {
+    type MarkerType = LadderBlockMarker;
+    type BlockMeshType = LadderMesh;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            GridDirection(self.direction),
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+        ));
+    }
+}
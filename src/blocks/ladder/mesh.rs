@@ -0,0 +1,54 @@
+use crate::blocks::block_traits::BlockMesh;
+use bevy::prelude::*;
+
+#[derive(Clone, Debug)]
+pub struct LadderMesh;
+
+#[derive(Resource, Deref, Clone, Debug)]
+pub struct LadderMeshHandle(Handle<Mesh>);
+
+impl From<Handle<Mesh>> for LadderMeshHandle {
+    fn from(value: Handle<Mesh>) -> Self {
+        Self(value)
+    }
+}
+
+impl BlockMesh for LadderMesh {
+    type Handle = LadderMeshHandle;
+
+    fn indices() -> Vec<u32> {
+        vec![
+            0, 1, 2, 0, 2, 3, // south (+z)
+        ]
+    }
+
+    fn positions() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // south (+z), flush against the north wall
+            [0.5, 0.5, -0.45],
+            [-0.5, 0.5, -0.45],
+            [-0.5, -0.5, -0.45],
+            [0.5, -0.5, -0.45],
+        ]
+    }
+
+    fn uvs() -> std::vec::Vec<[f32; 2]> {
+        vec![
+            // south (+z)
+            [1.0, 0.0],
+            [0.0, 0.0],
+            [0.0, 1.0],
+            [1.0, 1.0],
+        ]
+    }
+
+    fn normals() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // south (+z)
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+        ]
+    }
+}
@@ -0,0 +1,4 @@
+mod block;
+mod mesh;
+
+pub use block::{LadderBlock, LadderBlockMarker};
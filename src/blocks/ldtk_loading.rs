@@ -1,22 +1,22 @@
-use std::ops::Deref;
-
 use bevy::prelude::*;
 use bevy_asset_loader::asset_collection::AssetCollection;
-use bevy_ecs_ldtk::assets::{LdtkAssetPlugin, LdtkProject};
-
-use crate::{
-    blocks::{
-        BillboardBlock, BlockLayer, BlockMeshMap, BlockSpawnedFromLdtk, RampBlock,
-        RampFlippedBlock, TorchBlock, WallBlock,
-    },
-    GameStates,
+use bevy_ecs_ldtk::{
+    assets::{LdtkAssetPlugin, LdtkProject},
+    ldtk::loaded_level::LoadedLevel,
 };
+use image::DynamicImage;
+
+use crate::{blocks::BlockSpawnedFromLdtk, Flags, GameStates};
 
-use super::column::ColumnBlock;
+use super::{
+    block_registry::{split_entity_layers, BlockRegistry},
+    block_report::{BlockDiagnostic, BlockProblem, LevelReport},
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins(LdtkAssetPlugin)
-        .add_systems(OnEnter(GameStates::Playing), initial_load_system)
+        .init_resource::<LevelReport>()
+        .add_systems(OnExit(GameStates::Loading), initial_load_system)
         .add_systems(
             Update,
             level_load_system.run_if(in_state(GameStates::Playing)),
@@ -67,23 +67,9 @@ fn level_load_observer(
     mut meshes: ResMut<Assets<Mesh>>,
     images: ResMut<Assets<Image>>,
     handles: Res<LevelAssets>,
-    mesh_maps: Query<
-        (
-            Entity,
-            Option<&BlockMeshMap<WallBlock>>,
-            Option<&BlockMeshMap<RampBlock>>,
-            Option<&BlockMeshMap<RampFlippedBlock>>,
-            Option<&BlockMeshMap<BillboardBlock>>,
-            Option<&BlockMeshMap<ColumnBlock>>,
-        ),
-        Or<(
-            With<BlockMeshMap<WallBlock>>,
-            With<BlockMeshMap<RampBlock>>,
-            With<BlockMeshMap<RampFlippedBlock>>,
-            With<BlockMeshMap<BillboardBlock>>,
-            With<BlockMeshMap<ColumnBlock>>,
-        )>,
-    >,
+    registry: Res<BlockRegistry>,
+    mut report: ResMut<LevelReport>,
+    mut flags: ResMut<Flags>,
     spawned_from_ldtk: Query<Entity, With<BlockSpawnedFromLdtk>>,
     ldtk_assets: Res<Assets<LdtkProject>>,
 ) {
@@ -94,41 +80,8 @@ fn level_load_observer(
         commands.entity(entity).despawn_recursive();
     }
 
-    for (entity, w, r, rf, b, c) in &mesh_maps {
-        if let Some(w) = w {
-            for mesh in w.values() {
-                meshes.remove(mesh.deref());
-            }
-            commands.entity(entity).despawn_recursive();
-        };
-
-        if let Some(r) = r {
-            for mesh in r.values() {
-                meshes.remove(mesh.deref());
-            }
-            commands.entity(entity).despawn_recursive();
-        };
-
-        if let Some(rf) = rf {
-            for mesh in rf.values() {
-                meshes.remove(mesh.deref());
-            }
-            commands.entity(entity).despawn_recursive();
-        };
-
-        if let Some(b) = b {
-            for mesh in b.values() {
-                meshes.remove(mesh.deref());
-            }
-            commands.entity(entity).despawn_recursive();
-        };
-
-        if let Some(c) = c {
-            for mesh in c.values() {
-                meshes.remove(mesh.deref());
-            }
-            commands.entity(entity).despawn_recursive();
-        };
+    for registration in registry.registrations() {
+        commands.queue(registration.cleanup);
     }
 
     // TODO: Should only be added once, in a setup system.
@@ -141,57 +94,84 @@ fn level_load_observer(
         ..default()
     });
 
+    let mut diagnostics = vec![];
+
     if let Some(ldtk) = ldtk_assets.get(&handles.level) {
         for ref level in ldtk.as_standalone().iter_loaded_levels() {
-            for layer in level.layer_instances().iter() {
-                let Some((variant_str, _)) = layer.identifier.split_once('_') else {
-                    log::error!("FAILED TO PARSE: {}", layer.identifier);
-                    continue;
-                };
-
-                match variant_str {
-                    "walls" => BlockLayer::<WallBlock>::build(level, layer).spawn(
-                        commands.reborrow(),
-                        &mut meshes,
-                        &tileset,
-                        &material,
-                    ),
-                    "ramps" => BlockLayer::<RampBlock>::build(level, layer).spawn(
-                        commands.reborrow(),
-                        &mut meshes,
-                        &tileset,
-                        &material,
-                    ),
-                    "flippedramps" => BlockLayer::<RampFlippedBlock>::build(level, layer).spawn(
-                        commands.reborrow(),
-                        &mut meshes,
-                        &tileset,
-                        &material,
-                    ),
-                    "billboards" => BlockLayer::<BillboardBlock>::build(level, layer).spawn(
-                        commands.reborrow(),
-                        &mut meshes,
-                        &tileset,
-                        &material,
-                    ),
-                    "torches" => BlockLayer::<TorchBlock>::build(level, layer).spawn(
-                        commands.reborrow(),
-                        &mut meshes,
-                        &tileset,
-                        &material,
-                    ),
-                    "columns" => BlockLayer::<ColumnBlock>::build(level, layer).spawn(
-                        commands.reborrow(),
-                        &mut meshes,
-                        &tileset,
-                        &material,
-                    ),
-                    _ => {
-                        log::error!("FAILED TO PARSE: {}", layer.identifier);
-                        continue;
-                    }
-                };
-            }
+            diagnostics.extend(spawn_level(
+                level,
+                &registry,
+                commands.reborrow(),
+                &mut meshes,
+                &tileset,
+                &material,
+            ));
+        }
+    }
+
+    // skipped blocks are reported rather than panicking, so a typo can't take down a hot reload
+    for diagnostic in &diagnostics {
+        log::warn!("LEVEL REPORT: {diagnostic}");
+    }
+
+    flags.report = !diagnostics.is_empty();
+    report.0 = diagnostics;
+}
+
+// spawns every layer of a level through the block registry, returning the blocks it had to skip
+pub fn spawn_level(
+    level: &LoadedLevel,
+    registry: &BlockRegistry,
+    mut commands: Commands,
+    meshes: &mut ResMut<Assets<Mesh>>,
+    tileset: &DynamicImage,
+    material: &Handle<StandardMaterial>,
+) -> Vec<BlockDiagnostic> {
+    let mut diagnostics = vec![];
+
+    for layer in level.layer_instances().iter() {
+        let (entity_layers, remaining) = split_entity_layers(registry, layer);
+
+        for (registration, entity_layer) in entity_layers {
+            diagnostics.extend((registration.spawn)(
+                level,
+                &entity_layer,
+                commands.reborrow(),
+                meshes,
+                tileset,
+                material,
+            ));
         }
+
+        let layer = match remaining {
+            Some(ref remaining) if remaining.entity_instances.is_empty() => continue,
+            Some(ref remaining) => remaining,
+            None => layer,
+        };
+
+        let Some(registration) = layer
+            .identifier
+            .split_once('_')
+            .and_then(|(variant_str, _)| registry.get(variant_str))
+        else {
+            diagnostics.push(BlockDiagnostic {
+                level: level.identifier().clone(),
+                layer: layer.identifier.clone(),
+                iid: None,
+                problem: BlockProblem::UnknownLayer,
+            });
+            continue;
+        };
+
+        diagnostics.extend((registration.spawn)(
+            level,
+            layer,
+            commands.reborrow(),
+            meshes,
+            tileset,
+            material,
+        ));
     }
+
+    diagnostics
 }
@@ -4,24 +4,88 @@ mod billboard;
 mod blank_mesh;
 mod block_instances;
 mod block_layer;
+mod block_registry;
+mod block_report;
 mod block_traits;
 mod column;
+mod door;
+mod edge;
+mod item;
+mod ladder;
 mod ldtk_loading;
+mod npc;
+mod parapet;
+mod platform;
+mod pushable;
 mod ramp;
 mod ramp_flipped;
+mod references;
+mod spikes;
+mod switch;
+mod teleporter;
 mod torch;
 mod wall;
 
 pub use billboard::BillboardBlock;
-pub use block_instances::BlockSpawnedFromLdtk;
+pub use block_instances::{BlockSpawnedFromLdtk, LdtkIid};
 pub use block_layer::{BlockLayer, BlockMeshMap};
+pub use block_registry::{BlockRegistry, RegisterBlockExt};
+pub use block_report::{BlockDiagnostic, BlockProblem, LevelReport};
 pub use block_traits::BlockMesh;
-pub use ldtk_loading::LevelAssets;
+pub use door::DoorBlock;
+pub use edge::{CastleWindowBlock, LeftShapeBlock, MetalFenceBlock, RightShapeBlock};
+pub use item::ItemBlock;
+pub use ladder::{LadderBlock, LadderBlockMarker};
+pub use ldtk_loading::{spawn_level, LevelAssets};
+pub use npc::NpcBlock;
+pub use parapet::ParapetBlock;
+pub use platform::PlatformBlock;
+pub use pushable::PushableBlock;
 pub use ramp::{RampBlock, RampBlockMarker};
-pub use ramp_flipped::RampFlippedBlock;
+pub use ramp_flipped::{RampFlippedBlock, RampFlippedBlockMarker};
+pub use spikes::SpikesBlock;
+pub use switch::{LeverBlock, PressurePlateBlock, SwitchActivate, SwitchDeactivate};
+pub use teleporter::{TeleporterBlock, TeleporterDestinationIid};
 pub use torch::TorchBlock;
 pub use wall::WallBlock;
 
+use column::ColumnBlock;
+
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins((billboard::plugin, ldtk_loading::plugin, torch::plugin));
+    app.add_plugins((
+        billboard::plugin,
+        door::plugin,
+        ldtk_loading::plugin,
+        npc::plugin,
+        switch::plugin,
+        teleporter::plugin,
+        torch::plugin,
+        register_blocks,
+    ));
+}
+
+// kept apart from the plugin so tools can build a registry without loading any assets
+pub fn register_blocks(app: &mut App) {
+    app.register_block::<WallBlock>("walls")
+        .register_block::<RampBlock>("ramps")
+        .register_block::<RampFlippedBlock>("flippedramps")
+        .register_block::<BillboardBlock>("billboards")
+        .register_block::<TorchBlock>("torches")
+        .register_block::<ColumnBlock>("columns")
+        .register_block::<LadderBlock>("ladders")
+        .register_block::<DoorBlock>("doors")
+        .register_block::<LeverBlock>("levers")
+        .register_block::<PressurePlateBlock>("pressureplates")
+        .register_block::<PushableBlock>("pushables")
+        .register_block::<PlatformBlock>("platforms")
+        .register_block::<TeleporterBlock>("teleporters")
+        .register_block::<NpcBlock>("npcs")
+        .register_block::<ItemBlock>("items")
+        .register_block::<SpikesBlock>("spikes")
+        .register_block::<ParapetBlock>("parapets")
+        .register_entity_block::<MetalFenceBlock>("metalfence")
+        .register_entity_block::<CastleWindowBlock>("castlewindow")
+        .register_entity_block::<ParapetBlock>("parapet")
+        .register_entity_block::<LeftShapeBlock>("left_shape")
+        .register_entity_block::<RightShapeBlock>("right_shape");
 }
@@ -0,0 +1,155 @@
+use bevy::prelude::*;
+use rand::seq::SliceRandom;
+
+use crate::{
+    camera::PlayerCamera,
+    combat::Damage,
+    config::{NPC_DAMAGE, NPC_THINK_INTERVAL},
+    grid::{
+        Direction, GridClock, GridDirection, GridGravity, GridPosition, GridPositionMoveAttempt,
+        GridRng, GridSystemSet, GridTurnActor, GridWorld,
+    },
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        npc_behaviour_system.in_set(GridSystemSet::HandleInput),
+    );
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub enum NpcBehaviour {
+    #[default]
+    Idle,
+    Wander,
+    Patrol(NpcPatrol),
+    Follow,
+}
+
+#[derive(Clone, Debug)]
+pub struct NpcPatrol {
+    route: Vec<IVec3>,
+    origin: Option<IVec3>,
+    next: usize,
+}
+
+impl NpcPatrol {
+    pub fn new(route: Vec<IVec3>) -> Self {
+        Self {
+            route,
+            origin: None,
+            next: 0,
+        }
+    }
+
+    fn next_direction(
+        &mut self,
+        grid_world: &GridWorld,
+        position: &GridPosition,
+        facing: Option<&GridDirection>,
+        has_gravity: bool,
+    ) -> Option<GridDirection> {
+        if self.route.is_empty() {
+            return None;
+        }
+
+        let origin = *self.origin.get_or_insert(position.0);
+
+        if origin + self.route[self.next] == position.0 {
+            self.next = (self.next + 1) % self.route.len();
+        }
+
+        let waypoint = GridPosition(origin + self.route[self.next]);
+
+        grid_world
+            .find_path(position, facing, &waypoint, has_gravity)?
+            .first()
+            .copied()
+    }
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct NpcHostile;
+
+#[derive(Component, Deref, DerefMut, Clone, Debug)]
+#[require(GridTurnActor)]
+pub struct NpcThink(Timer);
+
+impl Default for NpcThink {
+    fn default() -> Self {
+        Self(Timer::new(NPC_THINK_INTERVAL, TimerMode::Repeating))
+    }
+}
+
+fn npc_behaviour_system(
+    mut commands: Commands,
+    mut npcs: Query<(
+        Entity,
+        &GridPosition,
+        Option<&GridDirection>,
+        Has<GridGravity>,
+        &mut NpcBehaviour,
+        &mut NpcThink,
+        Has<NpcHostile>,
+    )>,
+    player: Query<(Entity, &GridPosition), With<PlayerCamera>>,
+    grid_world: Res<GridWorld>,
+    clock: GridClock,
+    mut rng: ResMut<GridRng>,
+) {
+    let player = player.get_single().ok();
+    let player_position = player.map(|(_, position)| position.0);
+
+    for (entity, position, facing, has_gravity, mut behaviour, mut think, hostile) in &mut npcs {
+        if !clock.ready(entity, &mut think) {
+            continue;
+        }
+
+        // hostile npcs spend their turn hitting an adjacent player instead of moving,
+        // unless a fence or window between them would block the step
+        let adjacent = Direction::ALL.iter().any(|&direction| {
+            let direction = GridDirection(direction);
+            Some(position.forward(&direction).0) == player_position
+                && !grid_world.crossing_blocked(position, direction)
+        });
+
+        if let Some((player, _)) = player.filter(|_| hostile && adjacent) {
+            commands.trigger_targets(Damage(NPC_DAMAGE), player);
+            continue;
+        }
+
+        let direction = match behaviour.as_mut() {
+            NpcBehaviour::Idle => None,
+            NpcBehaviour::Wander => Direction::ALL
+                .choose(&mut **rng)
+                .copied()
+                .map(GridDirection),
+            NpcBehaviour::Patrol(patrol) => {
+                patrol.next_direction(&grid_world, position, facing, has_gravity)
+            }
+            NpcBehaviour::Follow => player_position
+                .and_then(|player_position| {
+                    grid_world.find_path(
+                        position,
+                        facing,
+                        &GridPosition(player_position),
+                        has_gravity,
+                    )
+                })
+                .filter(|path| path.len() > 1)
+                .and_then(|path| path.first().copied()),
+        };
+
+        // the player doesn't collide, so npcs step around it themselves
+        let Some(direction) =
+            direction.filter(|direction| Some(position.forward(direction).0) != player_position)
+        else {
+            continue;
+        };
+
+        commands
+            .entity(entity)
+            .trigger(GridPositionMoveAttempt(direction));
+    }
+}
@@ -0,0 +1,111 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::LdtkFields;
+
+use crate::{
+    animation::GridAnimated,
+    blocks::{
+        billboard::{BillboardFaceCamera, BillboardMesh},
+        block_layer::BlockSource,
+        block_report::BlockProblem,
+        block_traits::Block,
+    },
+    combat::Health,
+    config::NPC_HEALTH,
+    grid::{Direction, GridCollides, GridDirection, GridGravity},
+};
+
+use super::behaviour::{NpcBehaviour, NpcHostile, NpcPatrol, NpcThink};
+
+#[derive(Clone, Debug)]
+pub struct NpcBlock {
+    direction: Direction,
+    behaviour: NpcBehaviour,
+    hostile: bool,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct NpcBlockMarker;
+
+impl TryFrom<BlockSource> for NpcBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(entity_instance) => {
+                let direction = Direction::try_from(&entity_instance)?;
+
+                let behaviour = match entity_instance.get_enum_field("behaviour") {
+                    Ok(behaviour) => match behaviour.as_str() {
+                        "idle" => NpcBehaviour::Idle,
+                        "wander" => NpcBehaviour::Wander,
+                        "patrol" => {
+                            let mut route = vec![IVec3::ZERO];
+
+                            if let Ok(points) = entity_instance.get_points_field("patrol") {
+                                route.extend(points.into_iter().map(|point| {
+                                    IVec3::new(
+                                        point.x - entity_instance.grid.x,
+                                        0,
+                                        point.y - entity_instance.grid.y,
+                                    )
+                                }));
+                            }
+
+                            NpcBehaviour::Patrol(NpcPatrol::new(route))
+                        }
+                        "follow" => NpcBehaviour::Follow,
+                        value => {
+                            return Err(BlockProblem::InvalidEnum {
+                                field: "behaviour",
+                                value: value.to_string(),
+                            })
+                        }
+                    },
+                    Err(_) => NpcBehaviour::default(),
+                };
+
+                let hostile = entity_instance
+                    .get_bool_field("hostile")
+                    .is_ok_and(|&hostile| hostile);
+
+                Ok(Self {
+                    direction,
+                    behaviour,
+                    hostile,
+                })
+            }
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl Block for NpcBlock {
+    type MarkerType = NpcBlockMarker;
+    type BlockMeshType = BillboardMesh;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            GridDirection(self.direction),
+            GridAnimated::default(),
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            GridCollides,
+            GridGravity,
+            BillboardFaceCamera,
+            self.behaviour.clone(),
+            NpcThink::default(),
+            Health::new(NPC_HEALTH),
+        ));
+
+        if self.hostile {
+            entity.insert(NpcHostile);
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+
+mod behaviour;
+mod block;
+
+pub use block::NpcBlock;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(behaviour::plugin);
+}
@@ -0,0 +1,46 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+
+use crate::{
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
+    grid::{GridCollides, GridHalfHeight},
+};
+
+use super::mesh::ParapetMesh;
+
+#[derive(Clone, Debug)]
+pub struct ParapetBlock;
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct ParapetBlockMarker;
+
+impl TryFrom<BlockSource> for ParapetBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(_entity_instance) => Ok(Self),
+            BlockSource::Tile(_tile_instance) => Ok(Self),
+        }
+    }
+}
+
+impl Block for ParapetBlock {
+    type MarkerType = ParapetBlockMarker;
+    type BlockMeshType = ParapetMesh;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            GridCollides,
+            GridHalfHeight,
+        ));
+    }
+}
@@ -0,0 +1,72 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::LdtkFields;
+
+use crate::{
+    animation::GridAnimated,
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
+    grid::{GridCollides, GridDirection, GridPlatform},
+};
+
+use super::mesh::PlatformMesh;
+
+#[derive(Clone, Debug)]
+pub struct PlatformBlock {
+    path: Vec<IVec3>,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct PlatformBlockMarker;
+
+impl TryFrom<BlockSource> for PlatformBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(entity_instance) => {
+                let mut path = vec![IVec3::ZERO];
+
+                // waypoints are authored as level cells, stored relative to the platform's own cell
+                if let Ok(points) = entity_instance.get_points_field("path") {
+                    path.extend(points.into_iter().map(|point| {
+                        IVec3::new(
+                            point.x - entity_instance.grid.x,
+                            0,
+                            point.y - entity_instance.grid.y,
+                        )
+                    }));
+                }
+
+                // elevators travel between their own depth and `rise` levels above it
+                if let Ok(&rise) = entity_instance.get_int_field("rise") {
+                    path.push(IVec3::new(0, rise, 0));
+                }
+
+                Ok(Self { path })
+            }
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl Block for PlatformBlock {
+    type BlockMeshType = PlatformMesh;
+    type MarkerType = PlatformBlockMarker;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            GridDirection::default(),
+            GridAnimated::default(),
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            GridCollides,
+            GridPlatform::new(self.path.clone()),
+        ));
+    }
+}
@@ -0,0 +1,49 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+
+use crate::{
+    animation::GridAnimated,
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
+    grid::{GridCollides, GridDirection, GridPushable},
+};
+
+use super::mesh::PushableMesh;
+
+#[derive(Clone, Debug)]
+pub struct PushableBlock;
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct PushableBlockMarker;
+
+impl TryFrom<BlockSource> for PushableBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(_entity_instance) => Ok(Self),
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl Block for PushableBlock {
+    type BlockMeshType = PushableMesh;
+    type MarkerType = PushableBlockMarker;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            GridDirection::default(),
+            GridAnimated::default(),
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            GridCollides,
+            GridPushable,
+        ));
+    }
+}
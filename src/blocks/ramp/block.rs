@@ -3,7 +3,7 @@ use std::ops::Deref;
 use bevy::prelude::*;
 
 use crate::{
-    blocks::{block_layer::BlockSource, block_traits::Block},
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
     grid::{Direction, GridDirection},
 };
 
@@ -17,14 +17,16 @@ pub struct RampBlock {
 #[derive(Component, Clone, Debug, Default)]
 pub struct RampBlockMarker;
 
-impl From<BlockSource> for RampBlock {
-    fn from(value: BlockSource) -> Self {
+impl TryFrom<BlockSource> for RampBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
         match value {
             BlockSource::Entity(entity_instance) => {
-                let direction = Direction::from(&entity_instance);
-                Self { direction }
+                let direction = Direction::try_from(&entity_instance)?;
+                Ok(Self { direction })
             }
-            BlockSource::Tile(_tile_instance) => todo!(),
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
         }
     }
 }
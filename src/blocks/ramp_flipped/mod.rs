@@ -1,4 +1,4 @@
 mod block;
 mod mesh;
 
-pub use block::RampFlippedBlock;
+pub use block::{RampFlippedBlock, RampFlippedBlockMarker};
@@ -0,0 +1,77 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::Flags;
+
+use super::{
+    block_report::{BlockDiagnostic, BlockOrigin, BlockProblem, LevelReport},
+    LdtkIid,
+};
+
+// a component naming other blocks by LDtk iid, swapped for the entities once they have spawned
+pub trait IidReferences: Component + Sized {
+    type Resolved: Component;
+
+    const FIELD: &'static str;
+
+    fn iids(&self) -> &[String];
+
+    fn resolve(entities: Vec<Entity>) -> Option<Self::Resolved>;
+
+    fn resolve_plugin(app: &mut App) {
+        app.add_systems(Update, resolve_references_system::<Self>);
+    }
+}
+
+// references can point at blocks from any layer or level, so they are resolved once everything has spawned
+fn resolve_references_system<R: IidReferences>(
+    mut commands: Commands,
+    unresolved: Query<(Entity, &R, Option<&LdtkIid>, Option<&BlockOrigin>)>,
+    iids: Query<(Entity, &LdtkIid)>,
+    mut report: ResMut<LevelReport>,
+    mut flags: ResMut<Flags>,
+) {
+    if unresolved.is_empty() {
+        return;
+    }
+
+    let entities_by_iid = iids
+        .iter()
+        .map(|(entity, iid)| (iid.as_str(), entity))
+        .collect::<HashMap<_, _>>();
+
+    for (entity, references, iid, origin) in &unresolved {
+        let mut targets = vec![];
+
+        for target_iid in references.iids() {
+            if let Some(&target) = entities_by_iid.get(target_iid.as_str()) {
+                targets.push(target);
+                continue;
+            }
+
+            let diagnostic = BlockDiagnostic {
+                level: origin
+                    .map(|origin| origin.level.clone())
+                    .unwrap_or_default(),
+                layer: origin
+                    .map(|origin| origin.layer.clone())
+                    .unwrap_or_default(),
+                iid: iid.map(|iid| iid.0.clone()),
+                problem: BlockProblem::DanglingReference {
+                    field: R::FIELD,
+                    iid: target_iid.clone(),
+                },
+            };
+
+            log::warn!("LEVEL REPORT: {diagnostic}");
+            report.push(diagnostic);
+            flags.report = true;
+        }
+
+        let mut entity = commands.entity(entity);
+        entity.remove::<R>();
+
+        if let Some(resolved) = R::resolve(targets) {
+            entity.insert(resolved);
+        }
+    }
+}
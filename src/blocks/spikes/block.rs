@@ -0,0 +1,54 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::LdtkFields;
+
+use crate::{
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
+    combat::Hazard,
+    config::SPIKES_DAMAGE,
+};
+
+use super::mesh::SpikesMesh;
+
+#[derive(Clone, Debug)]
+pub struct SpikesBlock {
+    damage: i32,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct SpikesBlockMarker;
+
+impl TryFrom<BlockSource> for SpikesBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(entity_instance) => Ok(Self {
+                damage: entity_instance
+                    .get_int_field("damage")
+                    .copied()
+                    .unwrap_or(SPIKES_DAMAGE),
+            }),
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl Block for SpikesBlock {
+    type MarkerType = SpikesBlockMarker;
+    type BlockMeshType = SpikesMesh;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            Hazard(self.damage),
+        ));
+    }
+}
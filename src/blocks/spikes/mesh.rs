@@ -0,0 +1,70 @@
+use crate::blocks::block_traits::BlockMesh;
+use bevy::prelude::*;
+
+#[derive(Clone, Debug)]
+pub struct SpikesMesh;
+
+#[derive(Resource, Deref, Clone, Debug)]
+pub struct SpikesMeshHandle(Handle<Mesh>);
+
+impl From<Handle<Mesh>> for SpikesMeshHandle {
+    fn from(value: Handle<Mesh>) -> Self {
+        Self(value)
+    }
+}
+
+impl BlockMesh for SpikesMesh {
+    type Handle = SpikesMeshHandle;
+
+    fn indices() -> Vec<u32> {
+        vec![
+            0, 1, 2, 0, 2, 3, // diagonal (nw-se)
+            4, 5, 6, 4, 6, 7, // diagonal (ne-sw)
+        ]
+    }
+
+    fn positions() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // diagonal (nw-se), lower half of the cell
+            [0.5, 0.0, 0.5],
+            [-0.5, 0.0, -0.5],
+            [-0.5, -0.5, -0.5],
+            [0.5, -0.5, 0.5],
+            // diagonal (ne-sw), lower half of the cell
+            [-0.5, 0.0, 0.5],
+            [0.5, 0.0, -0.5],
+            [0.5, -0.5, -0.5],
+            [-0.5, -0.5, 0.5],
+        ]
+    }
+
+    fn uvs() -> std::vec::Vec<[f32; 2]> {
+        vec![
+            // diagonal (nw-se)
+            [1.0, 0.0],
+            [0.0, 0.0],
+            [0.0, 1.0],
+            [1.0, 1.0],
+            // diagonal (ne-sw)
+            [1.0, 0.0],
+            [0.0, 0.0],
+            [0.0, 1.0],
+            [1.0, 1.0],
+        ]
+    }
+
+    fn normals() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // diagonal (nw-se)
+            [0.707, 0.0, -0.707],
+            [0.707, 0.0, -0.707],
+            [0.707, 0.0, -0.707],
+            [0.707, 0.0, -0.707],
+            // diagonal (ne-sw)
+            [0.707, 0.0, 0.707],
+            [0.707, 0.0, 0.707],
+            [0.707, 0.0, 0.707],
+            [0.707, 0.0, 0.707],
+        ]
+    }
+}
@@ -0,0 +1,76 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+
+use crate::{
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
+    grid::{Direction, GridDirection, GridInteract, Interactable},
+};
+
+use super::{
+    mesh::LeverMesh,
+    wiring::{Switch, SwitchSet, SwitchTargetIids},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(lever_interact_observer);
+}
+
+#[derive(Clone, Debug)]
+pub struct LeverBlock {
+    direction: Direction,
+    targets: SwitchTargetIids,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct LeverBlockMarker;
+
+impl TryFrom<BlockSource> for LeverBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(entity_instance) => Ok(Self {
+                direction: Direction::try_from(&entity_instance)?,
+                targets: SwitchTargetIids::from(&entity_instance),
+            }),
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl Block for LeverBlock {
+    type MarkerType = LeverBlockMarker;
+    type BlockMeshType = LeverMesh;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            GridDirection(self.direction),
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            Switch::default(),
+            self.targets.clone(),
+            Interactable::new("pull lever"),
+        ));
+    }
+}
+
+fn lever_interact_observer(
+    trigger: Trigger<GridInteract>,
+    mut commands: Commands,
+    mut levers: Query<(&Switch, &mut Transform), With<LeverBlockMarker>>,
+) {
+    let Ok((switch, mut transform)) = levers.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    // mirror the sprite so the handle visibly flips
+    transform.scale.x = -transform.scale.x;
+
+    commands.trigger_targets(SwitchSet(!switch.active), trigger.entity());
+}
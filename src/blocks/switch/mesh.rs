@@ -0,0 +1,106 @@
+use crate::blocks::block_traits::BlockMesh;
+use bevy::prelude::*;
+
+#[derive(Clone, Debug)]
+pub struct LeverMesh;
+
+#[derive(Resource, Deref, Clone, Debug)]
+pub struct LeverMeshHandle(Handle<Mesh>);
+
+impl From<Handle<Mesh>> for LeverMeshHandle {
+    fn from(value: Handle<Mesh>) -> Self {
+        Self(value)
+    }
+}
+
+impl BlockMesh for LeverMesh {
+    type Handle = LeverMeshHandle;
+
+    fn indices() -> Vec<u32> {
+        vec![
+            0, 1, 2, 0, 2, 3, // south (+z)
+        ]
+    }
+
+    fn positions() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // south (+z), flush against the north wall
+            [0.5, 0.5, -0.48],
+            [-0.5, 0.5, -0.48],
+            [-0.5, -0.5, -0.48],
+            [0.5, -0.5, -0.48],
+        ]
+    }
+
+    fn uvs() -> std::vec::Vec<[f32; 2]> {
+        vec![
+            // south (+z)
+            [1.0, 0.0],
+            [0.0, 0.0],
+            [0.0, 1.0],
+            [1.0, 1.0],
+        ]
+    }
+
+    fn normals() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // south (+z)
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 1.0],
+        ]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PressurePlateMesh;
+
+#[derive(Resource, Deref, Clone, Debug)]
+pub struct PressurePlateMeshHandle(Handle<Mesh>);
+
+impl From<Handle<Mesh>> for PressurePlateMeshHandle {
+    fn from(value: Handle<Mesh>) -> Self {
+        Self(value)
+    }
+}
+
+impl BlockMesh for PressurePlateMesh {
+    type Handle = PressurePlateMeshHandle;
+
+    fn indices() -> Vec<u32> {
+        vec![
+            0, 3, 1, 1, 3, 2, // up (+y)
+        ]
+    }
+
+    fn positions() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // up (+y), resting on the floor
+            [-0.5, -0.48, -0.5],
+            [0.5, -0.48, -0.5],
+            [0.5, -0.48, 0.5],
+            [-0.5, -0.48, 0.5],
+        ]
+    }
+
+    fn uvs() -> std::vec::Vec<[f32; 2]> {
+        vec![
+            // up (+y)
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [1.0, 1.0],
+            [0.0, 1.0],
+        ]
+    }
+
+    fn normals() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // up (+y)
+            [0.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ]
+    }
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+
+mod lever;
+mod mesh;
+mod pressure_plate;
+mod wiring;
+
+pub use lever::LeverBlock;
+pub use pressure_plate::PressurePlateBlock;
+pub use wiring::{SwitchActivate, SwitchDeactivate};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins((lever::plugin, pressure_plate::plugin, wiring::plugin));
+}
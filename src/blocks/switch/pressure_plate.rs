@@ -0,0 +1,76 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+
+use crate::{
+    animation::GridAnimated,
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
+    grid::{GridPosition, GridPushable, GridSystemSet, GridWorld},
+};
+
+use super::{
+    mesh::PressurePlateMesh,
+    wiring::{Switch, SwitchSet, SwitchTargetIids},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Update, pressure_plate_system.after(GridSystemSet::Movement));
+}
+
+#[derive(Clone, Debug)]
+pub struct PressurePlateBlock {
+    targets: SwitchTargetIids,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct PressurePlateBlockMarker;
+
+impl TryFrom<BlockSource> for PressurePlateBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(entity_instance) => Ok(Self {
+                targets: SwitchTargetIids::from(&entity_instance),
+            }),
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl Block for PressurePlateBlock {
+    type MarkerType = PressurePlateBlockMarker;
+    type BlockMeshType = PressurePlateMesh;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            Switch::default(),
+            self.targets.clone(),
+        ));
+    }
+}
+
+fn pressure_plate_system(
+    mut commands: Commands,
+    plates: Query<(Entity, &GridPosition, &Switch), With<PressurePlateBlockMarker>>,
+    movers: Query<(), Or<(With<GridAnimated>, With<GridPushable>)>>,
+    grid_world: Res<GridWorld>,
+) {
+    // only things that can step on and off count, so scenery sharing the cell can't hold it down
+    for (entity, position, switch) in &plates {
+        let pressed = grid_world
+            .occupants(position)
+            .any(|occupant| occupant.entity != entity && movers.contains(occupant.entity));
+
+        if pressed != switch.active {
+            commands.trigger_targets(SwitchSet(pressed), entity);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use bevy_ecs_ldtk::{prelude::LdtkFields, EntityInstance};
+
+use crate::blocks::references::IidReferences;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(SwitchTargetIids::resolve_plugin)
+        .add_observer(switch_set_observer);
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct Switch {
+    pub active: bool,
+}
+
+#[derive(Component, Deref, Clone, Debug, Default)]
+pub struct SwitchTargetIids(Vec<String>);
+
+#[derive(Component, Deref, Clone, Debug, Default)]
+pub struct SwitchTargets(Vec<Entity>);
+
+#[derive(Event, Debug, Clone)]
+pub struct SwitchSet(pub bool);
+
+#[derive(Event, Debug, Clone)]
+pub struct SwitchActivate;
+
+#[derive(Event, Debug, Clone)]
+pub struct SwitchDeactivate;
+
+impl From<&EntityInstance> for SwitchTargetIids {
+    fn from(value: &EntityInstance) -> Self {
+        let iids = value
+            .get_entity_refs_field(Self::FIELD)
+            .map(|refs| {
+                refs.into_iter()
+                    .map(|entity_ref| entity_ref.entity_iid.clone())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self(iids)
+    }
+}
+
+impl IidReferences for SwitchTargetIids {
+    type Resolved = SwitchTargets;
+
+    const FIELD: &'static str = "targets";
+
+    fn iids(&self) -> &[String] {
+        &self.0
+    }
+
+    fn resolve(entities: Vec<Entity>) -> Option<Self::Resolved> {
+        Some(SwitchTargets(entities))
+    }
+}
+
+fn switch_set_observer(
+    trigger: Trigger<SwitchSet>,
+    mut commands: Commands,
+    mut switches: Query<(&mut Switch, Option<&SwitchTargets>)>,
+) {
+    let Ok((mut switch, targets)) = switches.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    if switch.active == trigger.0 {
+        return;
+    }
+
+    switch.active = trigger.0;
+
+    let Some(targets) = targets.filter(|targets| !targets.is_empty()) else {
+        return;
+    };
+
+    if switch.active {
+        commands.trigger_targets(SwitchActivate, targets.to_vec());
+    } else {
+        commands.trigger_targets(SwitchDeactivate, targets.to_vec());
+    }
+}
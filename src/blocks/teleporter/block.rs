@@ -0,0 +1,81 @@
+use std::ops::Deref;
+
+use bevy::prelude::*;
+use bevy_ecs_ldtk::prelude::LdtkFields;
+
+use crate::{
+    blocks::{
+        block_layer::BlockSource, block_report::BlockProblem, block_traits::Block,
+        references::IidReferences,
+    },
+    grid::GridTeleporter,
+};
+
+use super::mesh::TeleporterMesh;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins(TeleporterDestinationIid::resolve_plugin);
+}
+
+#[derive(Clone, Debug)]
+pub struct TeleporterBlock {
+    destination: String,
+}
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct TeleporterBlockMarker;
+
+#[derive(Component, Deref, Clone, Debug)]
+pub struct TeleporterDestinationIid(String);
+
+impl TryFrom<BlockSource> for TeleporterBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
+        match value {
+            BlockSource::Entity(entity_instance) => {
+                let destination = entity_instance
+                    .get_entity_ref_field(TeleporterDestinationIid::FIELD)
+                    .map(|entity_ref| entity_ref.entity_iid.clone())
+                    .map_err(|_| BlockProblem::MissingField(TeleporterDestinationIid::FIELD))?;
+
+                Ok(Self { destination })
+            }
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
+        }
+    }
+}
+
+impl Block for TeleporterBlock {
+    type BlockMeshType = TeleporterMesh;
+    type MarkerType = TeleporterBlockMarker;
+
+    fn specialize(
+        &self,
+        mut entity: EntityCommands,
+        mesh: &crate::blocks::block_traits::BlockMeshHandle<Self>,
+        material: &Handle<StandardMaterial>,
+    ) {
+        entity.insert((
+            Mesh3d(mesh.deref().clone()),
+            MeshMaterial3d(material.clone()),
+            TeleporterDestinationIid(self.destination.clone()),
+        ));
+    }
+}
+
+impl IidReferences for TeleporterDestinationIid {
+    type Resolved = GridTeleporter;
+
+    const FIELD: &'static str = "destination";
+
+    fn iids(&self) -> &[String] {
+        std::slice::from_ref(&self.0)
+    }
+
+    fn resolve(entities: Vec<Entity>) -> Option<Self::Resolved> {
+        entities
+            .first()
+            .map(|&destination| GridTeleporter { destination })
+    }
+}
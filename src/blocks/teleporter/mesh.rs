@@ -0,0 +1,54 @@
+use crate::blocks::block_traits::BlockMesh;
+use bevy::prelude::*;
+
+#[derive(Clone, Debug)]
+pub struct TeleporterMesh;
+
+#[derive(Resource, Deref, Clone, Debug)]
+pub struct TeleporterMeshHandle(Handle<Mesh>);
+
+impl From<Handle<Mesh>> for TeleporterMeshHandle {
+    fn from(value: Handle<Mesh>) -> Self {
+        Self(value)
+    }
+}
+
+impl BlockMesh for TeleporterMesh {
+    type Handle = TeleporterMeshHandle;
+
+    fn indices() -> Vec<u32> {
+        vec![
+            0, 3, 1, 1, 3, 2, // up (+y)
+        ]
+    }
+
+    fn positions() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // up (+y), resting on the floor
+            [-0.5, -0.49, -0.5],
+            [0.5, -0.49, -0.5],
+            [0.5, -0.49, 0.5],
+            [-0.5, -0.49, 0.5],
+        ]
+    }
+
+    fn uvs() -> std::vec::Vec<[f32; 2]> {
+        vec![
+            // up (+y)
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [1.0, 1.0],
+            [0.0, 1.0],
+        ]
+    }
+
+    fn normals() -> std::vec::Vec<[f32; 3]> {
+        vec![
+            // up (+y)
+            [0.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 1.0, 0.0],
+        ]
+    }
+}
@@ -3,7 +3,10 @@ use std::ops::Deref;
 use bevy::{prelude::*, render::view::RenderLayers};
 use bevy_hanabi::{ParticleEffect, ParticleEffectBundle};
 
-use crate::blocks::{blank_mesh::BlankMesh, block_layer::BlockSource, block_traits::Block};
+use crate::blocks::{
+    blank_mesh::BlankMesh, block_layer::BlockSource, block_report::BlockProblem,
+    block_traits::Block,
+};
 
 use super::particles::TorchParticleEffect;
 
@@ -18,11 +21,13 @@ pub struct TorchBlock;
 #[derive(Component, Clone, Debug, Default)]
 pub struct TorchBlockMarker;
 
-impl From<BlockSource> for TorchBlock {
-    fn from(value: BlockSource) -> Self {
+impl TryFrom<BlockSource> for TorchBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
         match value {
-            BlockSource::Entity(_entity_instance) => Self,
-            BlockSource::Tile(_tile_instance) => todo!(),
+            BlockSource::Entity(_entity_instance) => Ok(Self),
+            BlockSource::Tile(_tile_instance) => Err(BlockProblem::UnsupportedSource("tile")),
         }
     }
 }
@@ -1,7 +1,7 @@
 use std::ops::Deref;
 
 use crate::{
-    blocks::{block_layer::BlockSource, block_traits::Block},
+    blocks::{block_layer::BlockSource, block_report::BlockProblem, block_traits::Block},
     grid::GridCollides,
 };
 use bevy::prelude::*;
@@ -14,11 +14,13 @@ pub struct WallBlock;
 #[derive(Component, Clone, Debug, Default)]
 pub struct WallBlockMarker;
 
-impl From<BlockSource> for WallBlock {
-    fn from(value: BlockSource) -> Self {
+impl TryFrom<BlockSource> for WallBlock {
+    type Error = BlockProblem;
+
+    fn try_from(value: BlockSource) -> Result<Self, Self::Error> {
         match value {
-            BlockSource::Entity(_entity_instance) => todo!(),
-            BlockSource::Tile(_tile_instance) => Self,
+            BlockSource::Entity(_entity_instance) => Err(BlockProblem::UnsupportedSource("entity")),
+            BlockSource::Tile(_tile_instance) => Ok(Self),
         }
     }
 }
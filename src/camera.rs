@@ -1,7 +1,8 @@
 use std::f32::consts::PI;
 
 use bevy::{
-    core_pipeline::Skybox, gltf::Gltf, prelude::*, render::view::RenderLayers, utils::HashMap,
+    core_pipeline::Skybox, gltf::Gltf, input::InputSystem, prelude::*, render::view::RenderLayers,
+    utils::HashMap,
 };
 use bevy_asset_loader::asset_collection::AssetCollection;
 use bevy_persistent::{Persistent, StorageFormat};
@@ -11,24 +12,29 @@ use bevy_ratatui_camera::{
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    animation::{GridAnimated, ItemBobTween},
-    config::{PLAYER_STARTING_DIRECTION, PLAYER_STARTING_POSITION},
-    grid::{GridDirection, GridPosition},
+    animation::GridAnimated,
+    combat::Health,
+    config::{PLAYER_HEALTH, PLAYER_STARTING_DIRECTION, PLAYER_STARTING_POSITION},
+    grid::{GridDirection, GridGravity, GridMoveQueue, GridPosition, GridTeleportable},
+    held_item::HeldItems,
     GameStates,
 };
 
 pub(crate) fn plugin(app: &mut App) {
     app.init_resource::<KeysDown>()
         .add_systems(Startup, initialize_player_persist_system)
-        .add_systems(OnEnter(GameStates::Playing), setup_camera_system)
+        .add_systems(PreUpdate, keys_down_system.after(InputSystem))
+        .add_systems(OnExit(GameStates::Loading), setup_camera_system)
         .add_observer(update_player_persist_observer)
         .add_observer(clear_player_persist_observer);
 }
 
 #[derive(AssetCollection, Resource)]
 pub struct PlayerAssets {
-    #[asset(key = "player.sword")]
-    pub sword: Handle<Gltf>,
+    #[asset(key = "player.models", collection(typed, mapped))]
+    pub models: HashMap<String, Handle<Gltf>>,
+    #[asset(key = "player.items")]
+    pub held_items: Handle<HeldItems>,
 }
 
 #[derive(Resource, Serialize, Deserialize)]
@@ -61,16 +67,11 @@ pub struct WorldCamera;
 #[derive(Component)]
 pub struct BackgroundCamera;
 
-#[derive(Component)]
-pub struct Sword;
-
 #[derive(Resource, Default, Deref, DerefMut, Debug)]
 pub struct KeysDown(pub HashMap<KeyCode, f32>);
 
 fn setup_camera_system(
     mut commands: Commands,
-    handles: Res<PlayerAssets>,
-    assets_gltf: Res<Assets<Gltf>>,
     asset_server: Res<AssetServer>,
     persist: Res<Persistent<PlayerPersist>>,
 ) {
@@ -102,6 +103,10 @@ fn setup_camera_system(
             persist.position,
             persist.direction,
             GridAnimated::default(),
+            GridMoveQueue::default(),
+            GridGravity,
+            GridTeleportable,
+            Health::new(PLAYER_HEALTH),
         ))
         .with_children(|parent| {
             parent.spawn((
@@ -112,21 +117,6 @@ fn setup_camera_system(
                     ..default()
                 },
             ));
-            if let Some(gltf) = assets_gltf.get(&handles.sword) {
-                let mut sword_transform = Transform::default().with_scale(Vec3::new(0.4, 0.2, 0.4));
-                sword_transform.rotate_local_x(-1.5);
-                sword_transform.rotate_local_y(0.3);
-                sword_transform.rotate_local_z(-0.16);
-
-                parent.spawn((
-                    RenderLayers::layer(0), // setting this does not set gltf children
-                    SceneRoot(gltf.scenes[0].clone()),
-                    ItemBobTween,
-                    sword_transform,
-                    Sword,
-                ));
-            }
-
             parent.spawn((
                 WorldCamera,
                 RenderLayers::layer(1),
@@ -186,6 +176,18 @@ fn setup_camera_system(
         });
 }
 
+fn keys_down_system(
+    mut keys_down: ResMut<KeysDown>,
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+) {
+    keys_down.retain(|key, _| input.pressed(*key));
+
+    for &key in input.get_pressed() {
+        *keys_down.entry(key).or_default() += time.delta_secs();
+    }
+}
+
 fn initialize_player_persist_system(mut commands: Commands) {
     commands.insert_resource(
         Persistent::<PlayerPersist>::builder()
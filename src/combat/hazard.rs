@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+use crate::{
+    config::{FALL_DAMAGE_SAFE_DISTANCE, HAZARD_DAMAGE_INTERVAL},
+    grid::{GridDirection, GridFall, GridPosition, GridSystemSet, GridWorld, TimeMode},
+};
+
+use super::{Damage, Health};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(fall_damage_observer)
+        .add_systems(Update, hazard_system.after(GridSystemSet::Movement));
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Hazard(pub i32);
+
+#[derive(Component, Deref, DerefMut, Clone, Debug)]
+pub(super) struct HazardExposure(Timer);
+
+impl Default for HazardExposure {
+    fn default() -> Self {
+        Self(Timer::new(HAZARD_DAMAGE_INTERVAL, TimerMode::Repeating))
+    }
+}
+
+fn fall_damage_observer(
+    trigger: Trigger<GridFall>,
+    mut commands: Commands,
+    healths: Query<(), With<Health>>,
+) {
+    if !healths.contains(trigger.entity()) || trigger.0 <= FALL_DAMAGE_SAFE_DISTANCE {
+        return;
+    }
+
+    commands.trigger_targets(
+        Damage((trigger.0 - FALL_DAMAGE_SAFE_DISTANCE) as i32),
+        trigger.entity(),
+    );
+}
+
+// hazards hurt on entry and keep hurting whatever stays on them, every interval in real time
+// or on every move and turn in turn based mode
+fn hazard_system(
+    mut commands: Commands,
+    mut victims: Query<
+        (
+            Entity,
+            Ref<GridPosition>,
+            Option<Ref<GridDirection>>,
+            &mut HazardExposure,
+        ),
+        With<Health>,
+    >,
+    hazards: Query<&Hazard>,
+    grid_world: Res<GridWorld>,
+    mode: Res<TimeMode>,
+    time: Res<Time>,
+) {
+    for (entity, position, direction, mut exposure) in &mut victims {
+        let damage: i32 = grid_world
+            .occupants(&position)
+            .filter_map(|occupant| hazards.get(occupant.entity).ok())
+            .map(|hazard| hazard.0)
+            .sum();
+
+        if damage <= 0 {
+            continue;
+        }
+
+        let hurt = match *mode {
+            TimeMode::RealTime if position.is_changed() => {
+                exposure.reset();
+                true
+            }
+            TimeMode::RealTime => exposure.tick(time.delta()).just_finished(),
+            TimeMode::TurnBased => {
+                position.is_changed() || direction.is_some_and(|direction| direction.is_changed())
+            }
+        };
+
+        if hurt {
+            commands.trigger_targets(Damage(damage), entity);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use bevy::prelude::*;
+
+use crate::{
+    camera::PlayerCamera,
+    config::{POTION_HEAL, POTION_ITEM_ID},
+    inventory::ItemConsumed,
+};
+
+use super::hazard::HazardExposure;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(damage_observer)
+        .add_observer(death_despawn_observer)
+        .add_observer(potion_observer);
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+#[require(HazardExposure)]
+pub struct Health {
+    pub current: i32,
+    pub max: i32,
+}
+
+impl Health {
+    pub fn new(max: i32) -> Self {
+        Self { current: max, max }
+    }
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct Damage(pub i32);
+
+#[derive(Event, Debug, Clone)]
+pub struct Death;
+
+fn damage_observer(
+    trigger: Trigger<Damage>,
+    mut commands: Commands,
+    mut healths: Query<&mut Health>,
+) {
+    let Ok(mut health) = healths.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    if health.current <= 0 {
+        return;
+    }
+
+    health.current = (health.current - trigger.0).clamp(0, health.max);
+
+    if health.current == 0 {
+        commands.trigger_targets(Death, trigger.entity());
+    }
+}
+
+fn death_despawn_observer(
+    trigger: Trigger<Death>,
+    mut commands: Commands,
+    player: Query<(), With<PlayerCamera>>,
+) {
+    if player.contains(trigger.entity()) {
+        return;
+    }
+
+    commands.entity(trigger.entity()).despawn_recursive();
+}
+
+fn potion_observer(trigger: Trigger<ItemConsumed>, mut healths: Query<&mut Health>) {
+    if trigger.0 != POTION_ITEM_ID {
+        return;
+    }
+
+    if let Ok(mut health) = healths.get_mut(trigger.entity()) {
+        health.current = (health.current + POTION_HEAL).min(health.max);
+    }
+}
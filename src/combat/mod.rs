@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+mod hazard;
+mod health;
+mod respawn;
+mod sword;
+
+pub use hazard::Hazard;
+pub use health::{Damage, Health};
+pub use respawn::DamageFlash;
+pub use sword::{SwordAttack, SwordState, SwordSwing};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins((
+        hazard::plugin,
+        health::plugin,
+        respawn::plugin,
+        sword::plugin,
+    ));
+}
@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use bevy_persistent::Persistent;
+
+use crate::{
+    camera::{PlayerCamera, PlayerPersist},
+    config::{DAMAGE_FLASH_DURATION, RESPAWN_DELAY},
+    grid::{GridDirection, GridPosition, GridTeleport},
+    GameStates,
+};
+
+use super::{health::Death, Damage, Health};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<DamageFlash>()
+        .add_observer(player_damage_flash_observer)
+        .add_observer(player_death_observer)
+        .add_systems(OnEnter(GameStates::Dead), start_respawn_system)
+        .add_systems(
+            Update,
+            (
+                damage_flash_system,
+                respawn_system.run_if(in_state(GameStates::Dead)),
+            ),
+        );
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct DamageFlash(Option<Timer>);
+
+impl DamageFlash {
+    pub fn intensity(&self) -> f32 {
+        self.0
+            .as_ref()
+            .map_or(0., |timer| timer.fraction_remaining())
+    }
+}
+
+#[derive(Resource, Deref, DerefMut, Debug)]
+struct RespawnTimer(Timer);
+
+fn player_damage_flash_observer(
+    trigger: Trigger<Damage>,
+    mut flash: ResMut<DamageFlash>,
+    player: Query<(), With<PlayerCamera>>,
+) {
+    if player.contains(trigger.entity()) {
+        flash.0 = Some(Timer::new(DAMAGE_FLASH_DURATION, TimerMode::Once));
+    }
+}
+
+fn damage_flash_system(mut flash: ResMut<DamageFlash>, time: Res<Time>) {
+    if let Some(timer) = flash.0.as_mut() {
+        if timer.tick(time.delta()).finished() {
+            flash.0 = None;
+        }
+    }
+}
+
+fn player_death_observer(
+    trigger: Trigger<Death>,
+    mut next_state: ResMut<NextState<GameStates>>,
+    player: Query<(), With<PlayerCamera>>,
+) {
+    if player.contains(trigger.entity()) {
+        next_state.set(GameStates::Dead);
+    }
+}
+
+fn start_respawn_system(mut commands: Commands) {
+    commands.insert_resource(RespawnTimer(Timer::new(RESPAWN_DELAY, TimerMode::Once)));
+}
+
+// the persisted position doubles as the checkpoint, so saving with P also moves the respawn point
+fn respawn_system(
+    mut commands: Commands,
+    mut respawn: ResMut<RespawnTimer>,
+    mut next_state: ResMut<NextState<GameStates>>,
+    mut player: Query<(Entity, &GridPosition, &mut GridDirection, &mut Health), With<PlayerCamera>>,
+    persist: Res<Persistent<PlayerPersist>>,
+    time: Res<Time>,
+) {
+    if !respawn.tick(time.delta()).finished() {
+        return;
+    }
+
+    commands.remove_resource::<RespawnTimer>();
+    next_state.set(GameStates::Playing);
+
+    let Ok((entity, &position, mut direction, mut health)) = player.get_single_mut() else {
+        return;
+    };
+
+    health.current = health.max;
+    *direction = persist.direction;
+
+    commands.trigger_targets(
+        GridTeleport {
+            teleporter: position,
+            destination: persist.position,
+        },
+        entity,
+    );
+}
@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+use crate::{
+    animation::SwordSwingTween,
+    camera::PlayerCamera,
+    config::{SWORD_COOLDOWN, SWORD_DAMAGE, SWORD_STRIKE_DELAY},
+    grid::{GridDirection, GridPosition, GridSystemSet, GridWorld},
+    held_item::EquippedItem,
+    GameStates,
+};
+
+use super::{Damage, Health};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(sword_attack_observer).add_systems(
+        Update,
+        (
+            sword_input_system.before(GridSystemSet::Movement),
+            sword_strike_system.after(GridSystemSet::Movement),
+        )
+            .run_if(in_state(GameStates::Playing)),
+    );
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct SwordAttack;
+
+#[derive(Event, Debug, Clone)]
+pub struct SwordSwing;
+
+#[derive(Component, Default, Debug, Clone)]
+pub struct SwordState {
+    cooldown: Option<Timer>,
+    strike: Option<Timer>,
+}
+
+fn sword_input_system(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    player: Query<Entity, With<PlayerCamera>>,
+) {
+    let Ok(player) = player.get_single() else {
+        return;
+    };
+
+    if input.just_pressed(KeyCode::Space) {
+        commands.trigger_targets(SwordAttack, player);
+    }
+}
+
+fn sword_attack_observer(
+    _trigger: Trigger<SwordAttack>,
+    mut commands: Commands,
+    mut swords: Query<(Entity, &Transform, &mut SwordState), With<EquippedItem>>,
+) {
+    let Ok((entity, transform, mut state)) = swords.get_single_mut() else {
+        return;
+    };
+
+    if state.cooldown.is_some() {
+        return;
+    }
+
+    state.cooldown = Some(Timer::new(SWORD_COOLDOWN, TimerMode::Once));
+    state.strike = Some(Timer::new(SWORD_STRIKE_DELAY, TimerMode::Once));
+
+    commands
+        .entity(entity)
+        .insert(SwordSwingTween {
+            rest_rotation: transform.rotation,
+        })
+        .trigger(SwordSwing);
+}
+
+fn sword_strike_system(
+    mut commands: Commands,
+    mut swords: Query<&mut SwordState, With<EquippedItem>>,
+    player: Query<(Entity, &GridPosition, &GridDirection), With<PlayerCamera>>,
+    healths: Query<(), With<Health>>,
+    grid_world: Res<GridWorld>,
+    time: Res<Time>,
+) {
+    for mut state in &mut swords {
+        if let Some(cooldown) = state.cooldown.as_mut() {
+            if cooldown.tick(time.delta()).finished() {
+                state.cooldown = None;
+            }
+        }
+
+        let Some(strike) = state.strike.as_mut() else {
+            continue;
+        };
+
+        if !strike.tick(time.delta()).finished() {
+            continue;
+        }
+
+        state.strike = None;
+
+        let Ok((player, position, direction)) = player.get_single() else {
+            continue;
+        };
+
+        // the hit lands when the blade reaches the end of its arc
+        let targets: Vec<Entity> = grid_world
+            .faced_cells(position, direction)
+            .flat_map(|cell| grid_world.occupants(&cell))
+            .map(|occupant| occupant.entity)
+            .filter(|&entity| entity != player && healths.contains(entity))
+            .collect();
+
+        for target in targets {
+            commands.trigger_targets(Damage(SWORD_DAMAGE), target);
+        }
+    }
+}
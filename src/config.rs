@@ -1,6 +1,49 @@
+use std::time::Duration;
+
 use bevy::math::IVec3;
 
-use crate::grid::Direction;
+use crate::grid::{Direction, TimeMode};
 
 pub static PLAYER_STARTING_POSITION: IVec3 = IVec3::new(4, 1, 7);
 pub static PLAYER_STARTING_DIRECTION: Direction = Direction::North;
+pub static PLAYER_HEALTH: i32 = 5;
+pub static RESPAWN_DELAY: Duration = Duration::from_millis(1500);
+pub static DAMAGE_FLASH_DURATION: Duration = Duration::from_millis(400);
+pub static DAMAGE_FLASH_STRENGTH: f32 = 0.6;
+pub static POTION_ITEM_ID: &str = "potion";
+pub static POTION_HEAL: i32 = 2;
+
+pub static MESSAGE_DURATION: Duration = Duration::from_millis(2500);
+
+pub static GRID_MOVE_DURATION: Duration = Duration::from_millis(666);
+pub static GRID_MOVE_QUEUE_DEPTH: usize = 2;
+pub static GRID_MOVE_QUEUE_BLEND: bool = false;
+pub static GRID_MOVE_BLEND_FRACTION: f32 = 0.5;
+pub static GRID_MAX_FALL_DISTANCE: i32 = 32;
+pub static GRID_BUMP_FRACTION: f32 = 1. / 3.;
+pub static GRID_EDGE_BUMP_FRACTION: f32 = 0.2;
+pub static FALL_DAMAGE_SAFE_DISTANCE: u32 = 2;
+pub static SPIKES_DAMAGE: i32 = 1;
+pub static HAZARD_DAMAGE_INTERVAL: Duration = Duration::from_millis(1000);
+
+pub static GRID_HOLD_INITIAL_DELAY: f32 = 0.35;
+pub static GRID_HOLD_REPEAT_INTERVAL: f32 = 0.2;
+// without the kitty protocol, a second press within the delay is the terminal's key repeat,
+// and a hold ends once repeats stop arriving for the timeout
+pub static TERMINAL_KEY_REPEAT_DELAY: f32 = 0.6;
+pub static TERMINAL_KEY_REPEAT_TIMEOUT: f32 = 0.15;
+
+pub static GRID_PLATFORM_STEP_INTERVAL: Duration = Duration::from_millis(1000);
+
+pub static GRID_PATHFINDING_MAX_NODES: usize = 4096;
+
+pub static NPC_THINK_INTERVAL: Duration = Duration::from_millis(1200);
+pub static NPC_HEALTH: i32 = 3;
+pub static NPC_DAMAGE: i32 = 1;
+
+pub static SWORD_COOLDOWN: Duration = Duration::from_millis(500);
+pub static SWORD_STRIKE_DELAY: Duration = Duration::from_millis(120);
+pub static SWORD_DAMAGE: i32 = 1;
+
+pub static TIME_MODE: TimeMode = TimeMode::RealTime;
+pub static TURN_RNG_SEED: u64 = 0x5eed;
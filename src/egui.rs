@@ -10,9 +10,19 @@ use egui_ratatui::RataguiBackend;
 use ratatui::Terminal;
 
 use crate::{
+    blocks::LevelReport,
     camera::{BackgroundCamera, PlayerCamera, PlayerPersist, WorldCamera},
-    grid::{GridDirection, GridPosition},
-    widgets::debug_frame::debug_frame,
+    combat::{DamageFlash, Health},
+    config::DAMAGE_FLASH_STRENGTH,
+    grid::{FacedInteractable, GridDirection, GridPosition, Interactable, TimeMode},
+    inventory::Inventory,
+    message::StatusMessage,
+    widgets::{
+        damage_flash::damage_flash,
+        debug_frame::{debug_frame, PlayerStatus},
+        inventory_panel::inventory_panel,
+        level_report::level_report,
+    },
     Flags,
 };
 
@@ -47,7 +57,19 @@ fn draw_scene_system(
     diagnostics: Res<DiagnosticsStore>,
     kitty_enabled: Option<Res<KittyEnabled>>,
     persist: Res<Persistent<PlayerPersist>>,
+    faced: Res<FacedInteractable>,
+    interactables: Query<&Interactable>,
+    time_mode: Res<TimeMode>,
+    health: Query<&Health, With<PlayerCamera>>,
+    flash: Res<DamageFlash>,
+    inventory: Res<Persistent<Inventory>>,
+    message: Res<StatusMessage>,
+    report: Res<LevelReport>,
 ) -> io::Result<()> {
+    let interact_prompt = faced
+        .and_then(|entity| interactables.get(entity).ok())
+        .map(|interactable| interactable.prompt.as_str());
+
     ratagui.draw(|frame| {
         let area = debug_frame(
             frame,
@@ -56,6 +78,12 @@ fn draw_scene_system(
             kitty_enabled.as_deref(),
             player.get_single().ok(),
             &persist,
+            PlayerStatus {
+                interact_prompt,
+                message: message.text(),
+                time_mode: *time_mode,
+                health: health.get_single().ok(),
+            },
             false,
         );
 
@@ -68,6 +96,22 @@ fn draw_scene_system(
         if let Ok(w) = player_widget.get_single() {
             w.render_autoresize(area, frame.buffer_mut(), &mut commands);
         }
+
+        if flash.intensity() > 0. {
+            damage_flash(
+                frame.buffer_mut(),
+                area,
+                flash.intensity() * DAMAGE_FLASH_STRENGTH,
+            );
+        }
+
+        if flags.inventory {
+            inventory_panel(frame, area, &inventory);
+        }
+
+        if flags.report && !report.is_empty() {
+            level_report(frame, area, &report);
+        }
     })?;
 
     CentralPanel::default()
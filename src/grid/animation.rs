@@ -1,16 +1,21 @@
 use bevy::prelude::*;
 
 use crate::{
-    animation::{GridAnimated, GridMoveBlockedTween, GridMoveTween},
-    blocks::RampBlockMarker,
+    animation::{
+        GridAnimated, GridClimbTween, GridFallTween, GridMoveBlockedTween, GridMoveTween,
+        GridWarpTween,
+    },
+    config::{GRID_BUMP_FRACTION, GRID_EDGE_BUMP_FRACTION},
 };
 
 use super::{
-    utilities::find_ramp_position_direction, Direction, GridDirection, GridPosition, GridSystemSet,
+    edge::GridEdgeBlocked, movement::GridMoveStyle, Direction, GridDirection, GridMoveQueue,
+    GridPosition, GridSystemSet, GridWorld,
 };
 
 pub(super) fn plugin(app: &mut App) {
     app.add_observer(grid_movement_blocked_observer)
+        .add_observer(grid_edge_blocked_observer)
         .add_systems(
             PostUpdate,
             (
@@ -32,21 +37,53 @@ pub struct GridMoveBlockedParent;
 
 fn grid_movement_blocked_observer(
     trigger: Trigger<GridMoveBlocked>,
-    mut commands: Commands,
+    commands: Commands,
+    grid_positions: Query<(&Transform, &GridDirection, &GridAnimated)>,
+) {
+    insert_blocked_tween(
+        commands,
+        &grid_positions,
+        trigger.entity(),
+        trigger.event().0,
+        GRID_BUMP_FRACTION,
+    );
+}
+
+// thin edge blocks sit just inside the cell edge, so the bump stops well short of it
+fn grid_edge_blocked_observer(
+    trigger: Trigger<GridEdgeBlocked>,
+    commands: Commands,
     grid_positions: Query<(&Transform, &GridDirection, &GridAnimated)>,
 ) {
-    let (transform, grid_direction, grid_animated) = grid_positions.get(trigger.entity()).unwrap();
+    insert_blocked_tween(
+        commands,
+        &grid_positions,
+        trigger.entity(),
+        trigger.event().0,
+        GRID_EDGE_BUMP_FRACTION,
+    );
+}
 
-    commands.entity(trigger.entity()).remove::<GridMoveTween>();
+fn insert_blocked_tween(
+    mut commands: Commands,
+    grid_positions: &Query<(&Transform, &GridDirection, &GridAnimated)>,
+    entity: Entity,
+    blocked_position: GridPosition,
+    bump_fraction: f32,
+) {
+    let (transform, grid_direction, grid_animated) = grid_positions.get(entity).unwrap();
 
     commands
-        .entity(trigger.entity())
-        .insert(GridMoveBlockedTween {
-            start_position: grid_animated.previous_position,
-            blocked_position: trigger.event().0,
-            start_rotation: transform.rotation,
-            end_rotation: grid_direction.into(),
-        });
+        .entity(entity)
+        .remove::<(GridMoveTween, GridFallTween, GridClimbTween, GridWarpTween)>();
+
+    commands.entity(entity).insert(GridMoveBlockedTween {
+        start_position: grid_animated.previous_position,
+        blocked_position,
+        bump_fraction,
+        start_rotation: transform.rotation,
+        end_rotation: grid_direction.into(),
+    });
 }
 
 fn grid_animated_movement_system(
@@ -58,17 +95,74 @@ fn grid_animated_movement_system(
             &GridDirection,
             &Transform,
             &mut GridAnimated,
+            Option<&GridMoveQueue>,
+            Option<&GridMoveStyle>,
         ),
         (Or<(Changed<GridPosition>, Changed<GridDirection>)>,),
     >,
 ) {
-    for (entity, &position, direction, transform, mut animated) in &mut grid_position_changed {
+    for (entity, &position, direction, transform, mut animated, queue, style) in
+        &mut grid_position_changed
+    {
         let previous = animated.update_previous(position);
 
+        let start_translation = if queue.is_some_and(|queue| queue.blend) {
+            animated.buffer_transform
+        } else {
+            previous.into()
+        };
+
         commands.entity(entity).remove::<GridMoveBlockedTween>();
 
+        match style {
+            Some(GridMoveStyle::Fall { ledge }) => {
+                commands
+                    .entity(entity)
+                    .remove::<(GridMoveStyle, GridMoveTween, GridClimbTween, GridWarpTween)>()
+                    .insert(GridFallTween {
+                        start_translation,
+                        ledge_position: *ledge,
+                        end_position: position,
+                        start_rotation: transform.rotation,
+                        end_rotation: direction.into(),
+                    });
+                continue;
+            }
+            Some(GridMoveStyle::Climb(vertical)) => {
+                commands
+                    .entity(entity)
+                    .remove::<(GridMoveStyle, GridMoveTween, GridFallTween, GridWarpTween)>()
+                    .insert(GridClimbTween {
+                        start_translation,
+                        end_position: position,
+                        vertical: *vertical,
+                        start_rotation: transform.rotation,
+                        end_rotation: direction.into(),
+                    });
+                continue;
+            }
+            Some(GridMoveStyle::Warp { teleporter }) => {
+                commands
+                    .entity(entity)
+                    .remove::<(GridMoveStyle, GridMoveTween, GridFallTween, GridClimbTween)>()
+                    .insert(GridWarpTween {
+                        start_translation,
+                        teleporter_position: *teleporter,
+                        end_position: position,
+                        start_rotation: transform.rotation,
+                        end_rotation: direction.into(),
+                    });
+                continue;
+            }
+            None => {}
+        }
+
+        commands
+            .entity(entity)
+            .remove::<(GridFallTween, GridClimbTween, GridWarpTween)>();
+
         commands.entity(entity).insert(GridMoveTween {
-            start_position: previous,
+            start_translation,
             end_position: position,
             start_rotation: transform.rotation,
             end_rotation: direction.into(),
@@ -77,17 +171,27 @@ fn grid_animated_movement_system(
 }
 
 fn ramp_height_correction_system(
-    mut positioned: Query<(&mut Transform, &GridAnimated)>,
-    ramps: Query<(&GridPosition, &GridDirection), With<RampBlockMarker>>,
+    mut positioned: Query<(&mut Transform, &GridAnimated, Option<&GridMoveTween>)>,
+    grid_world: Res<GridWorld>,
 ) {
-    for (mut transform, animated) in &mut positioned {
+    for (mut transform, animated, tween) in &mut positioned {
         let current_position = GridPosition::from(animated.buffer_transform);
-        if let Some((ramp_position, ramp_direction)) =
-            find_ramp_position_direction(&current_position, &ramps)
-        {
+
+        // an underside stair cuts straight through the flipped ramp's open side, so there is no
+        // floor to follow until the move is over
+        let on_underside_stair = tween.is_some_and(|tween| {
+            grid_world.underside_stair(
+                &GridPosition::from(tween.start_translation),
+                &tween.end_position,
+            )
+        });
+
+        if on_underside_stair {
+            transform.translation.y = animated.buffer_transform.y;
+        } else if let Some(ramp_direction) = grid_world.ramp(&current_position) {
             let base_height = Vec3::from(current_position).y;
-            let ramp_diff_z = animated.buffer_transform.z - ramp_position.z as f32;
-            let ramp_diff_x = animated.buffer_transform.x - ramp_position.x as f32;
+            let ramp_diff_z = animated.buffer_transform.z - current_position.z as f32;
+            let ramp_diff_x = animated.buffer_transform.x - current_position.x as f32;
             let ramp_height = match ramp_direction.0 {
                 Direction::North => 0.5 - ramp_diff_z,
                 Direction::East => 0.5 + ramp_diff_x,
@@ -96,8 +200,10 @@ fn ramp_height_correction_system(
             };
 
             transform.translation.y = base_height + ramp_height;
+        } else if grid_world.has_floor(&current_position) {
+            transform.translation.y = (current_position.y as f32).max(animated.buffer_transform.y);
         } else {
-            transform.translation.y = current_position.y as f32;
+            transform.translation.y = animated.buffer_transform.y;
         }
 
         transform.translation.x = animated.buffer_transform.x;
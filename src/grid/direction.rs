@@ -4,7 +4,9 @@ use bevy::prelude::*;
 use bevy_ecs_ldtk::{prelude::LdtkFields, EntityInstance};
 use serde::{Deserialize, Serialize};
 
-#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+use crate::blocks::BlockProblem;
+
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Direction {
     #[default]
     North,
@@ -14,6 +16,13 @@ pub enum Direction {
 }
 
 impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
     pub fn left(&self) -> Self {
         match self {
             Direction::North => Direction::West,
@@ -37,21 +46,32 @@ impl Direction {
     }
 }
 
-impl From<&EntityInstance> for Direction {
-    fn from(value: &EntityInstance) -> Self {
-        value
-            .get_enum_field("direction")
-            .map(|dir| match dir.as_str() {
-                "north" => Direction::North,
-                "east" => Direction::East,
-                "south" => Direction::South,
-                "west" => Direction::West,
-                _ => unreachable!(),
-            })
-            .unwrap_or(Direction::North)
+impl TryFrom<&EntityInstance> for Direction {
+    type Error = BlockProblem;
+
+    fn try_from(value: &EntityInstance) -> Result<Self, Self::Error> {
+        match value.get_enum_field("direction") {
+            Ok(direction) => match direction.as_str() {
+                "north" => Ok(Direction::North),
+                "east" => Ok(Direction::East),
+                "south" => Ok(Direction::South),
+                "west" => Ok(Direction::West),
+                value => Err(BlockProblem::InvalidEnum {
+                    field: "direction",
+                    value: value.to_string(),
+                }),
+            },
+            Err(_) => Ok(Direction::North),
+        }
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerticalDirection {
+    Up,
+    Down,
+}
+
 #[derive(
     Component, Clone, Copy, Debug, Default, PartialEq, Eq, Deref, DerefMut, Serialize, Deserialize,
 )]
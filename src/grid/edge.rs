@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+use super::{GridDirection, GridPosition, GridWorld};
+
+// blocks crossing some sides of its cell rather than the whole cell, given relative
+// to the block's `GridDirection` so `front` is the edge it faces
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EdgeCollider {
+    pub front: bool,
+    pub right: bool,
+    pub back: bool,
+    pub left: bool,
+}
+
+impl EdgeCollider {
+    pub const FRONT: Self = Self {
+        front: true,
+        right: false,
+        back: false,
+        left: false,
+    };
+
+    pub fn blocks(&self, facing: GridDirection, edge: GridDirection) -> bool {
+        (self.front && edge == facing)
+            || (self.right && edge == facing.right())
+            || (self.back && edge == facing.reverse())
+            || (self.left && edge == facing.left())
+    }
+}
+
+#[derive(Event, Default, Debug, Clone)]
+pub struct GridEdgeBlocked(pub GridPosition);
+
+impl GridWorld {
+    pub fn blocks_edge(&self, position: &GridPosition, edge: GridDirection) -> bool {
+        self.occupants(position)
+            .filter_map(|occupant| occupant.edge_collider)
+            .any(|(collider, facing)| collider.blocks(facing, edge))
+    }
+
+    // an edge can be blocked from the cell on either side of it
+    pub fn crossing_blocked(&self, position: &GridPosition, direction: GridDirection) -> bool {
+        self.blocks_edge(position, direction)
+            || self.blocks_edge(&position.forward(&direction), direction.reverse())
+    }
+}
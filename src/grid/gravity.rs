@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::config::GRID_MAX_FALL_DISTANCE;
+
+use super::{GridPosition, GridWorld};
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct GridGravity;
+
+#[derive(Event, Default, Debug, Clone)]
+pub struct GridFall(pub u32);
+
+impl GridWorld {
+    pub fn supports(&self, position: &GridPosition) -> bool {
+        self.occupants(position)
+            .any(|occupant| occupant.collides && !occupant.half_height)
+    }
+
+    pub fn has_floor(&self, position: &GridPosition) -> bool {
+        self.supports(&position.down()) || self.ramp(&position.down()).is_some()
+    }
+
+    pub fn landing(&self, ledge: &GridPosition) -> Option<GridPosition> {
+        let mut position = *ledge;
+
+        for _ in 0..=GRID_MAX_FALL_DISTANCE {
+            if self.has_collider(&position) {
+                return None;
+            }
+            if self.supports(&position.down()) {
+                return Some(position);
+            }
+            position = position.down();
+        }
+
+        None
+    }
+}
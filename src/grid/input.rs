@@ -1,10 +1,13 @@
 use bevy::prelude::*;
 
-use crate::camera::{PersistClearEvent, PersistEvent, PlayerCamera};
+use crate::{
+    camera::{KeysDown, PersistClearEvent, PersistEvent, PlayerCamera},
+    config::{GRID_HOLD_INITIAL_DELAY, GRID_HOLD_REPEAT_INTERVAL},
+};
 
 use super::{
-    direction::GridDirection,
-    movement::{GridDirectionMove, GridPositionMoveAttempt},
+    interact::{FacedInteractable, GridInteract},
+    queue::{GridMoveInput, GridMoveQueue},
     GridSystemSet,
 };
 
@@ -17,31 +20,26 @@ pub(super) fn plugin(app: &mut App) {
 
 pub fn handle_grid_movement_input_system(
     mut commands: Commands,
-    mut camera_in_grid: Query<(Entity, &GridDirection), With<PlayerCamera>>,
+    mut camera_in_grid: Query<(Entity, &mut GridMoveQueue), With<PlayerCamera>>,
     input: Res<ButtonInput<KeyCode>>,
+    keys_down: Res<KeysDown>,
+    faced: Res<FacedInteractable>,
+    time: Res<Time>,
 ) {
-    let (entity, grid_direction) = camera_in_grid.single_mut();
+    let (entity, mut queue) = camera_in_grid.single_mut();
     let mut entity = commands.entity(entity);
 
     for press in input.get_just_pressed() {
+        if let Some(move_input) = grid_move_input(press) {
+            queue.push(move_input);
+            continue;
+        }
+
         match press {
-            KeyCode::KeyW => {
-                entity.trigger(GridPositionMoveAttempt(*grid_direction));
-            }
-            KeyCode::KeyD => {
-                entity.trigger(GridPositionMoveAttempt(grid_direction.right()));
-            }
-            KeyCode::KeyS => {
-                entity.trigger(GridPositionMoveAttempt(grid_direction.reverse()));
-            }
-            KeyCode::KeyA => {
-                entity.trigger(GridPositionMoveAttempt(grid_direction.left()));
-            }
-            KeyCode::KeyQ => {
-                entity.trigger(GridDirectionMove(grid_direction.left()));
-            }
-            KeyCode::KeyE => {
-                entity.trigger(GridDirectionMove(grid_direction.right()));
+            KeyCode::KeyF => {
+                if let Some(target) = **faced {
+                    entity.commands().trigger_targets(GridInteract, target);
+                }
             }
             KeyCode::KeyP => {
                 entity.trigger(PersistEvent);
@@ -52,4 +50,38 @@ pub fn handle_grid_movement_input_system(
             _ => {}
         }
     }
+
+    for (key, &held) in keys_down.iter() {
+        let Some(move_input) = grid_move_input(key) else {
+            continue;
+        };
+
+        if queue.is_empty() && hold_repeat_due(held, time.delta_secs()) {
+            queue.push(move_input);
+        }
+    }
+}
+
+fn grid_move_input(key: &KeyCode) -> Option<GridMoveInput> {
+    match key {
+        KeyCode::KeyW => Some(GridMoveInput::Forward),
+        KeyCode::KeyD => Some(GridMoveInput::Right),
+        KeyCode::KeyS => Some(GridMoveInput::Back),
+        KeyCode::KeyA => Some(GridMoveInput::Left),
+        KeyCode::KeyQ => Some(GridMoveInput::TurnLeft),
+        KeyCode::KeyE => Some(GridMoveInput::TurnRight),
+        _ => None,
+    }
+}
+
+fn hold_repeat_due(held: f32, delta: f32) -> bool {
+    let repeats = |duration: f32| {
+        if duration < GRID_HOLD_INITIAL_DELAY {
+            0
+        } else {
+            ((duration - GRID_HOLD_INITIAL_DELAY) / GRID_HOLD_REPEAT_INTERVAL) as u32 + 1
+        }
+    };
+
+    repeats(held) > repeats(held - delta)
 }
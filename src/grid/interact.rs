@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+
+use crate::camera::PlayerCamera;
+
+use super::{GridDirection, GridPosition, GridSystemSet, GridWorld};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<FacedInteractable>().add_systems(
+        Update,
+        faced_interactable_system.in_set(GridSystemSet::Movement),
+    );
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct Interactable {
+    pub prompt: String,
+}
+
+impl Interactable {
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+        }
+    }
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct GridInteract;
+
+#[derive(Resource, Deref, Clone, Copy, Debug, Default, PartialEq)]
+pub struct FacedInteractable(Option<Entity>);
+
+impl GridWorld {
+    pub fn faced_cells(
+        &self,
+        position: &GridPosition,
+        direction: &GridDirection,
+    ) -> impl Iterator<Item = GridPosition> {
+        let ahead = position.forward(direction);
+
+        let ramp_adjusted = if self.ramp(position) == Some(*direction) {
+            Some(ahead.up())
+        } else if self.ramp(&ahead.down()) == Some(direction.reverse()) {
+            Some(ahead.down())
+        } else {
+            None
+        };
+
+        std::iter::once(ahead).chain(ramp_adjusted)
+    }
+}
+
+fn faced_interactable_system(
+    mut faced: ResMut<FacedInteractable>,
+    player: Query<(&GridPosition, &GridDirection), With<PlayerCamera>>,
+    interactables: Query<(), With<Interactable>>,
+    grid_world: Res<GridWorld>,
+) {
+    let target = player.get_single().ok().and_then(|(position, direction)| {
+        grid_world
+            .faced_cells(position, direction)
+            .flat_map(|cell| grid_world.occupants(&cell))
+            .map(|occupant| occupant.entity)
+            .find(|&entity| interactables.contains(entity))
+    });
+
+    faced.set_if_neq(FacedInteractable(target));
+}
@@ -0,0 +1,52 @@
+use super::{GridDirection, GridPosition, GridWorld, VerticalDirection};
+
+pub fn ladder_destination(
+    grid_world: &GridWorld,
+    position: &GridPosition,
+    facing: Option<&GridDirection>,
+    direction: GridDirection,
+) -> Option<(GridPosition, VerticalDirection)> {
+    if let Some(ladder_direction) = grid_world
+        .ladder(position)
+        .filter(|ladder_direction| Some(ladder_direction) == facing)
+    {
+        if direction == ladder_direction {
+            let above = position.up();
+
+            if grid_world.has_collider(&above) {
+                return None;
+            }
+
+            if grid_world.ladder(&above) == Some(ladder_direction) {
+                return Some((above, VerticalDirection::Up));
+            }
+
+            let top = above.forward(&ladder_direction);
+
+            return (!grid_world.has_collider(&top)
+                && grid_world.supports(&top.down())
+                && !grid_world.crossing_blocked(&above, ladder_direction))
+            .then_some((top, VerticalDirection::Up));
+        }
+
+        if direction == ladder_direction.reverse() {
+            let below = position.down();
+
+            return (grid_world.ladder(&below) == Some(ladder_direction)
+                && !grid_world.has_collider(&below))
+            .then_some((below, VerticalDirection::Down));
+        }
+
+        return None;
+    }
+
+    // stepping off a ledge onto the top of a ladder that faces back towards the mover
+    let over = position.forward(&direction);
+    let below = over.down();
+
+    (grid_world.ladder(&below) == Some(direction.reverse())
+        && !grid_world.has_collider(&over)
+        && !grid_world.has_collider(&below)
+        && !grid_world.crossing_blocked(position, direction))
+    .then_some((below, VerticalDirection::Down))
+}
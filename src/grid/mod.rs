@@ -2,15 +2,37 @@ use bevy::prelude::*;
 
 mod animation;
 mod direction;
+mod edge;
+mod gravity;
 mod input;
+mod interact;
+mod ladder;
 mod movement;
+mod pathfinding;
+mod platform;
 mod position;
-mod utilities;
+mod push;
+mod queue;
+mod teleport;
+mod turn;
+mod world;
 
+pub use animation::GridMoveBlocked;
 use bevy_tween::TweenSystemSet;
-pub use direction::{Direction, GridDirection};
-pub use movement::{GridCollides, GridDirectionMove, GridPositionMoveAttempt};
+pub use direction::{Direction, GridDirection, VerticalDirection};
+pub use edge::EdgeCollider;
+pub use gravity::{GridFall, GridGravity};
+pub use interact::{FacedInteractable, GridInteract, Interactable};
+pub use movement::{
+    step_destination, GridCollides, GridDirectionMove, GridHalfHeight, GridPositionMoveAttempt,
+};
+pub use platform::GridPlatform;
 pub use position::GridPosition;
+pub use push::GridPushable;
+pub use queue::GridMoveQueue;
+pub use teleport::{GridTeleport, GridTeleportable, GridTeleporter};
+pub use turn::{GridClock, GridRng, GridTurnActor, TimeMode};
+pub use world::GridWorld;
 
 use crate::GameStates;
 
@@ -34,5 +56,15 @@ pub(super) fn plugin(app: &mut App) {
             .after(TweenSystemSet::ApplyTween)
             .run_if(in_state(GameStates::Playing)),
     )
-    .add_plugins((animation::plugin, input::plugin, movement::plugin));
+    .add_plugins((
+        animation::plugin,
+        input::plugin,
+        interact::plugin,
+        movement::plugin,
+        platform::plugin,
+        queue::plugin,
+        teleport::plugin,
+        turn::plugin,
+        world::plugin,
+    ));
 }
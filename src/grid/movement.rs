@@ -1,11 +1,14 @@
 use bevy::prelude::*;
 
-use crate::{animation::GridAnimated, blocks::RampBlockMarker};
+use crate::animation::GridAnimated;
 
 use super::{
     animation::GridMoveBlocked,
-    utilities::{find_collider_position, find_ramp_position_direction},
-    GridDirection, GridPosition, GridSystemSet,
+    edge::GridEdgeBlocked,
+    gravity::{GridFall, GridGravity},
+    ladder::ladder_destination,
+    push::{push_destination, GridPushable},
+    GridDirection, GridPosition, GridSystemSet, GridWorld, VerticalDirection,
 };
 
 pub(super) fn plugin(app: &mut App) {
@@ -34,6 +37,17 @@ pub struct GridDirectionMove(pub GridDirection);
 #[derive(Component, Debug, Clone)]
 pub struct GridCollides;
 
+// a collider that blocks movement into its cell but is too low to stand on top of
+#[derive(Component, Debug, Clone)]
+pub struct GridHalfHeight;
+
+#[derive(Component, Debug, Clone)]
+pub enum GridMoveStyle {
+    Fall { ledge: GridPosition },
+    Climb(VerticalDirection),
+    Warp { teleporter: GridPosition },
+}
+
 fn grid_position_setup_observer(
     trigger: Trigger<OnAdd, GridPosition>,
     mut commands: Commands,
@@ -51,66 +65,248 @@ fn grid_position_setup_observer(
 fn grid_movement_observer(
     trigger: Trigger<GridPositionMove>,
     mut grid_position: Query<&mut GridPosition>,
+    mut grid_world: ResMut<GridWorld>,
 ) {
     if let Ok(mut grid_position) = grid_position.get_mut(trigger.entity()) {
         *grid_position = trigger.0;
+        grid_world.relocate(trigger.entity(), trigger.0);
     }
 }
 
 fn grid_movement_attempt_observer(
     trigger: Trigger<GridPositionMoveAttempt>,
     mut commands: Commands,
-    grid_positions: Query<&GridPosition>,
-    colliders: Query<&GridPosition, With<GridCollides>>,
-    ramps: Query<(&GridPosition, &GridDirection), With<RampBlockMarker>>,
+    grid_positions: Query<(&GridPosition, Option<&GridDirection>, Has<GridGravity>)>,
+    pushables: Query<(), With<GridPushable>>,
+    grid_world: Res<GridWorld>,
 ) {
-    if let Ok(mover_position) = grid_positions.get(trigger.entity()) {
+    if let Ok((mover_position, facing, has_gravity)) = grid_positions.get(trigger.entity()) {
         let mut entity = commands.entity(trigger.entity());
 
-        let ramp_direction = find_ramp_position_direction(mover_position, &ramps)
-            .map(|(_, ramp_direction)| *ramp_direction);
-
-        let source_edge_heights = mover_position.edge_heights(trigger.0, ramp_direction);
-
-        for destination in [
-            mover_position.forward(&trigger.0).up(),
-            mover_position.forward(&trigger.0),
-            mover_position.forward(&trigger.0).down(),
-        ] {
-            let ramp_direction = find_ramp_position_direction(&destination, &ramps)
-                .map(|(_, ramp_direction)| *ramp_direction);
-
-            let has_ramp = ramp_direction.is_some();
-            let has_collider = find_collider_position(&destination, &colliders).is_some();
-            let has_collider_above =
-                find_collider_position(&destination.up(), &colliders).is_some();
-            let has_collider_below =
-                find_collider_position(&destination.down(), &colliders).is_some();
-
-            let destination_edge_heights =
-                destination.edge_heights(trigger.0.reverse(), ramp_direction);
-
-            let edge_matches =
-                source_edge_heights == (destination_edge_heights.1, destination_edge_heights.0);
-
-            if edge_matches
-                && has_collider_below
-                && !has_collider
-                && !(has_ramp && has_collider_above)
-            {
+        match grid_step(
+            &grid_world,
+            mover_position,
+            facing,
+            trigger.0,
+            has_gravity,
+            |pushable| pushables.contains(pushable),
+        ) {
+            Some(GridStep::Climb(destination, vertical)) => {
+                entity
+                    .insert(GridMoveStyle::Climb(vertical))
+                    .trigger(GridPositionMove(destination));
+
+                if let Some(ladder_direction) = grid_world
+                    .ladder(&destination)
+                    .filter(|&ladder_direction| Some(&ladder_direction) != facing)
+                {
+                    entity.trigger(GridDirectionMove(ladder_direction));
+                }
+            }
+            Some(GridStep::EdgeBlocked) => {
+                entity.trigger(GridEdgeBlocked(mover_position.forward(&trigger.0)));
+            }
+            Some(GridStep::Walk(destination)) => {
+                entity.trigger(GridPositionMove(destination));
+            }
+            Some(GridStep::Push {
+                pushable,
+                pushable_destination,
+                destination,
+            }) => {
+                entity
+                    .commands()
+                    .entity(pushable)
+                    .trigger(GridPositionMove(pushable_destination));
                 entity.trigger(GridPositionMove(destination));
-                return;
+            }
+            Some(GridStep::Fall { ledge, landing }) => {
+                entity
+                    .insert(GridMoveStyle::Fall { ledge })
+                    .trigger(GridPositionMove(landing))
+                    .trigger(GridFall((ledge.y - landing.y) as u32));
+            }
+            None => {
+                if grid_world.ramp(mover_position) == Some(trigger.0) {
+                    entity.trigger(GridMoveBlocked(mover_position.forward(&trigger.0).up()));
+                } else {
+                    entity.trigger(GridMoveBlocked(mover_position.forward(&trigger.0)));
+                }
             }
         }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum GridStep {
+    Climb(GridPosition, VerticalDirection),
+    EdgeBlocked,
+    Walk(GridPosition),
+    Push {
+        pushable: Entity,
+        pushable_destination: GridPosition,
+        destination: GridPosition,
+    },
+    Fall {
+        ledge: GridPosition,
+        landing: GridPosition,
+    },
+}
+
+// the outcome of one attempted step, shared by movement and pathfinding so they can't disagree
+pub fn grid_step(
+    grid_world: &GridWorld,
+    position: &GridPosition,
+    facing: Option<&GridDirection>,
+    direction: GridDirection,
+    has_gravity: bool,
+    is_pushable: impl Fn(Entity) -> bool,
+) -> Option<GridStep> {
+    if let Some((destination, vertical)) =
+        ladder_destination(grid_world, position, facing, direction)
+    {
+        return Some(GridStep::Climb(destination, vertical));
+    }
+
+    if grid_world.crossing_blocked(position, direction) {
+        return Some(GridStep::EdgeBlocked);
+    }
+
+    if let Some(destination) = step_destination(grid_world, position, direction, None) {
+        return Some(GridStep::Walk(destination));
+    }
+
+    if let Some((pushable, pushable_destination, destination)) =
+        push_destination(grid_world, is_pushable, position, direction)
+    {
+        return Some(GridStep::Push {
+            pushable,
+            pushable_destination,
+            destination,
+        });
+    }
+
+    if !has_gravity {
+        return None;
+    }
+
+    let ledge = position.forward(&direction);
+    let edge_matches =
+        position.edge_heights(direction, grid_world.ramp(position)) == (ledge.y, ledge.y);
 
-        if ramp_direction.is_some_and(|ramp_direction| ramp_direction.eq(&trigger.0)) {
-            entity.trigger(GridMoveBlocked(mover_position.forward(&trigger.0).up()));
+    grid_world
+        .landing(&ledge)
+        .filter(|_| edge_matches && grid_world.ramp(&ledge).is_none())
+        .map(|landing| GridStep::Fall { ledge, landing })
+}
+
+pub fn step_destination(
+    grid_world: &GridWorld,
+    position: &GridPosition,
+    direction: GridDirection,
+    ignore: Option<Entity>,
+) -> Option<GridPosition> {
+    if grid_world.crossing_blocked(position, direction) {
+        return None;
+    }
+
+    let source_edge_heights = position.edge_heights(direction, grid_world.ramp(position));
+
+    [
+        position.forward(&direction).up(),
+        position.forward(&direction),
+        position.forward(&direction).down(),
+    ]
+    .into_iter()
+    .find(|destination| {
+        let destination_edge_heights =
+            destination.edge_heights(direction.reverse(), grid_world.ramp(destination));
+
+        let edge_matches =
+            source_edge_heights == (destination_edge_heights.1, destination_edge_heights.0);
+
+        edge_matches && can_enter(grid_world, destination, direction, ignore)
+    })
+    .or_else(|| underside_step_destination(grid_world, position, direction, ignore))
+}
+
+// a flipped ramp overhead that opens ahead is a stair up through its open side, and one ahead
+// that opens back towards the mover is the same stair taken back down
+fn underside_step_destination(
+    grid_world: &GridWorld,
+    position: &GridPosition,
+    direction: GridDirection,
+    ignore: Option<Entity>,
+) -> Option<GridPosition> {
+    let ahead = position.forward(&direction);
+
+    let destination = if grid_world.flipped_ramp(&position.up()) == Some(direction) {
+        ahead.up()
+    } else if grid_world.flipped_ramp(&ahead) == Some(direction.reverse()) {
+        ahead.down()
+    } else {
+        return None;
+    };
+
+    let on_flat_floor =
+        grid_world.ramp(position).is_none() && grid_world.ramp(&destination).is_none();
+
+    (on_flat_floor && can_enter(grid_world, &destination, direction, ignore)).then_some(destination)
+}
+
+impl GridWorld {
+    pub fn underside_stair(&self, from: &GridPosition, to: &GridPosition) -> bool {
+        let (lower, upper) = if from.y < to.y {
+            (from, to)
         } else {
-            entity.trigger(GridMoveBlocked(mover_position.forward(&trigger.0)));
-        }
+            (to, from)
+        };
+
+        self.flipped_ramp(&lower.up())
+            .is_some_and(|direction| lower.forward(&direction).up().0 == upper.0)
     }
 }
 
+fn can_enter(
+    grid_world: &GridWorld,
+    destination: &GridPosition,
+    direction: GridDirection,
+    ignore: Option<Entity>,
+) -> bool {
+    let ramp_direction = grid_world.ramp(destination);
+
+    let has_collider = grid_world
+        .occupants(destination)
+        .any(|occupant| occupant.collides && Some(occupant.entity) != ignore);
+    let has_collider_below = grid_world.supports(&destination.down());
+    let has_headroom = has_headroom(grid_world, destination, ramp_direction, direction);
+    let edge_blocked = grid_world.blocks_edge(destination, direction.reverse());
+
+    has_collider_below && !has_collider && has_headroom && !edge_blocked
+}
+
+fn has_headroom(
+    grid_world: &GridWorld,
+    destination: &GridPosition,
+    ramp_direction: Option<GridDirection>,
+    direction: GridDirection,
+) -> bool {
+    let ceiling = destination.up();
+
+    if !grid_world.has_collider(&ceiling) {
+        return true;
+    }
+
+    let flipped_ramp_direction = grid_world.flipped_ramp(&ceiling);
+
+    [direction, direction.reverse()].into_iter().all(|edge| {
+        let floor_heights = destination.edge_heights(edge, ramp_direction);
+        let ceiling_heights = destination.ceiling_edge_heights(edge, flipped_ramp_direction);
+
+        ceiling_heights.0 > floor_heights.0 && ceiling_heights.1 > floor_heights.1
+    })
+}
+
 fn grid_static_position_movement_system(
     mut grid_position_changed: Query<
         (&GridPosition, &mut Transform),
@@ -0,0 +1,389 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+use crate::config::GRID_PATHFINDING_MAX_NODES;
+
+use super::{
+    movement::{grid_step, GridStep},
+    Direction, GridDirection, GridPosition, GridWorld,
+};
+
+// ladders only take a walker facing them, so the search tracks facing alongside position
+type PathNode = (GridPosition, Option<GridDirection>);
+
+impl GridWorld {
+    pub fn find_path(
+        &self,
+        start: &GridPosition,
+        facing: Option<&GridDirection>,
+        goal: &GridPosition,
+        has_gravity: bool,
+    ) -> Option<Vec<GridDirection>> {
+        // a fall is one step however far it drops, so only horizontal distance is a safe estimate
+        let heuristic = |position: &GridPosition| (goal.0 - position.0).xz().abs().element_sum();
+
+        let start = (*start, facing.copied());
+        let mut nodes = vec![start];
+        let mut open = BinaryHeap::new();
+        let mut came_from: HashMap<usize, (usize, GridDirection)> = HashMap::default();
+        let mut costs: HashMap<(IVec3, Option<Direction>), i32> = HashMap::default();
+
+        open.push(Reverse((heuristic(&start.0), 0, 0)));
+        costs.insert(Self::node_key(start), 0);
+
+        let mut expanded = 0;
+
+        while let Some(Reverse((_, cost, index))) = open.pop() {
+            let node = nodes[index];
+
+            if node.0 .0 == goal.0 {
+                return Some(Self::reconstruct_path(&came_from, index));
+            }
+
+            if cost > costs[&Self::node_key(node)] {
+                continue;
+            }
+
+            expanded += 1;
+            if expanded > GRID_PATHFINDING_MAX_NODES {
+                return None;
+            }
+
+            for direction in Direction::ALL.map(GridDirection) {
+                let Some(next) = self.walk_step(node, direction, has_gravity) else {
+                    continue;
+                };
+
+                let next_cost = cost + 1;
+                let key = Self::node_key(next);
+                if next_cost < costs.get(&key).copied().unwrap_or(i32::MAX) {
+                    costs.insert(key, next_cost);
+                    nodes.push(next);
+                    came_from.insert(nodes.len() - 1, (index, direction));
+                    open.push(Reverse((
+                        next_cost + heuristic(&next.0),
+                        next_cost,
+                        nodes.len() - 1,
+                    )));
+                }
+            }
+        }
+
+        None
+    }
+
+    // every cell a walker with gravity can get to from `start`, plus any extra `links` such as
+    // teleporters that the movement rules alone don't know about. the walker can turn in place,
+    // so every facing is tried from each cell
+    pub fn reachable(
+        &self,
+        start: &GridPosition,
+        links: &HashMap<IVec3, Vec<IVec3>>,
+    ) -> HashSet<IVec3> {
+        let mut reached = HashSet::default();
+        let mut open = vec![start.0];
+        reached.insert(start.0);
+
+        while let Some(position) = open.pop() {
+            let steps = Direction::ALL
+                .map(GridDirection)
+                .into_iter()
+                .flat_map(|facing| {
+                    Direction::ALL
+                        .map(GridDirection)
+                        .into_iter()
+                        .filter_map(move |direction| {
+                            self.walk_step((GridPosition(position), Some(facing)), direction, true)
+                        })
+                        .map(|(next, _)| next.0)
+                });
+            let linked = links.get(&position).into_iter().flatten().copied();
+
+            for next in steps.chain(linked) {
+                if reached.insert(next) {
+                    open.push(next);
+                }
+            }
+        }
+
+        reached
+    }
+
+    // where a step takes a walker and which way it faces afterwards. paths never count on
+    // pushing blocks out of the way
+    fn walk_step(
+        &self,
+        (position, facing): PathNode,
+        direction: GridDirection,
+        has_gravity: bool,
+    ) -> Option<PathNode> {
+        match grid_step(
+            self,
+            &position,
+            facing.as_ref(),
+            direction,
+            has_gravity,
+            |_| false,
+        )? {
+            GridStep::Climb(destination, _) => {
+                Some((destination, self.ladder(&destination).or(facing)))
+            }
+            GridStep::Walk(destination) => Some((destination, facing)),
+            GridStep::Fall { landing, .. } => Some((landing, facing)),
+            GridStep::EdgeBlocked | GridStep::Push { .. } => None,
+        }
+    }
+
+    fn node_key((position, facing): PathNode) -> (IVec3, Option<Direction>) {
+        (position.0, facing.map(|facing| facing.0))
+    }
+
+    fn reconstruct_path(
+        came_from: &HashMap<usize, (usize, GridDirection)>,
+        goal: usize,
+    ) -> Vec<GridDirection> {
+        let mut path = vec![];
+        let mut index = goal;
+
+        while let Some(&(previous, direction)) = came_from.get(&index) {
+            path.push(direction);
+            index = previous;
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{prelude::*, utils::HashMap};
+
+    use crate::{
+        blocks::{LadderBlockMarker, RampBlockMarker},
+        grid::{
+            movement::{grid_step, GridStep},
+            Direction, EdgeCollider, GridCollides, GridDirection, GridPosition, GridWorld,
+        },
+    };
+
+    const EAST: GridDirection = GridDirection(Direction::East);
+    const WEST: GridDirection = GridDirection(Direction::West);
+    const SOUTH: GridDirection = GridDirection(Direction::South);
+
+    fn solid(world: &mut World, cells: impl IntoIterator<Item = IVec3>) {
+        for cell in cells {
+            world.spawn((GridPosition(cell), GridCollides));
+        }
+    }
+
+    fn path(
+        grid_world: &GridWorld,
+        start: IVec3,
+        facing: GridDirection,
+        goal: IVec3,
+        has_gravity: bool,
+    ) -> Option<Vec<GridDirection>> {
+        grid_world.find_path(
+            &GridPosition(start),
+            Some(&facing),
+            &GridPosition(goal),
+            has_gravity,
+        )
+    }
+
+    #[test]
+    fn ramp_leads_up_a_level() {
+        let mut world = World::new();
+        solid(&mut world, (0..5).map(|x| IVec3::new(x, 0, 0)));
+        solid(&mut world, [IVec3::new(3, 1, 0), IVec3::new(4, 1, 0)]);
+
+        let without_ramp = GridWorld::from_world(&mut world);
+        assert_eq!(
+            path(
+                &without_ramp,
+                IVec3::new(0, 1, 0),
+                EAST,
+                IVec3::new(4, 2, 0),
+                true
+            ),
+            None
+        );
+
+        world.spawn((GridPosition(IVec3::new(2, 1, 0)), EAST, RampBlockMarker));
+        let grid_world = GridWorld::from_world(&mut world);
+
+        assert_eq!(
+            path(
+                &grid_world,
+                IVec3::new(0, 1, 0),
+                EAST,
+                IVec3::new(4, 2, 0),
+                true
+            ),
+            Some(vec![EAST; 4])
+        );
+        assert_eq!(
+            path(
+                &grid_world,
+                IVec3::new(4, 2, 0),
+                WEST,
+                IVec3::new(0, 1, 0),
+                true
+            ),
+            Some(vec![WEST; 4])
+        );
+    }
+
+    #[test]
+    fn ladder_needs_to_be_faced() {
+        let mut world = World::new();
+        solid(&mut world, (0..3).map(|x| IVec3::new(x, 0, 0)));
+        solid(&mut world, [IVec3::new(2, 1, 0), IVec3::new(2, 2, 0)]);
+        for y in 1..3 {
+            world.spawn((GridPosition(IVec3::new(1, y, 0)), EAST, LadderBlockMarker));
+        }
+        let grid_world = GridWorld::from_world(&mut world);
+
+        assert_eq!(
+            path(
+                &grid_world,
+                IVec3::new(0, 1, 0),
+                EAST,
+                IVec3::new(2, 3, 0),
+                true
+            ),
+            Some(vec![EAST; 3])
+        );
+        assert_eq!(
+            path(
+                &grid_world,
+                IVec3::new(0, 1, 0),
+                WEST,
+                IVec3::new(2, 3, 0),
+                true
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn falls_only_with_gravity() {
+        let mut world = World::new();
+        solid(&mut world, (0..2).map(|x| IVec3::new(x, 0, 0)));
+        solid(&mut world, (2..4).map(|x| IVec3::new(x, -3, 0)));
+        let grid_world = GridWorld::from_world(&mut world);
+
+        assert!(matches!(
+            grid_step(&grid_world, &GridPosition(IVec3::new(1, 1, 0)), Some(&EAST), EAST, true, |_| false),
+            Some(GridStep::Fall { landing, .. }) if landing.0 == IVec3::new(2, -2, 0)
+        ));
+        assert_eq!(
+            path(
+                &grid_world,
+                IVec3::new(0, 1, 0),
+                EAST,
+                IVec3::new(3, -2, 0),
+                true
+            ),
+            Some(vec![EAST; 3])
+        );
+        assert_eq!(
+            path(
+                &grid_world,
+                IVec3::new(0, 1, 0),
+                EAST,
+                IVec3::new(3, -2, 0),
+                false
+            ),
+            None
+        );
+
+        let reached = grid_world.reachable(&GridPosition(IVec3::new(0, 1, 0)), &HashMap::default());
+        assert!(reached.contains(&IVec3::new(3, -2, 0)));
+        assert!(!grid_world
+            .reachable(&GridPosition(IVec3::new(3, -2, 0)), &HashMap::default())
+            .contains(&IVec3::new(0, 1, 0)));
+    }
+
+    #[test]
+    fn edge_collider_blocks_one_side() {
+        let mut world = World::new();
+        solid(&mut world, (0..3).map(|x| IVec3::new(x, 0, 0)));
+        world.spawn((GridPosition(IVec3::new(1, 1, 0)), EAST, EdgeCollider::FRONT));
+        let grid_world = GridWorld::from_world(&mut world);
+
+        assert!(matches!(
+            grid_step(&grid_world, &GridPosition(IVec3::new(0, 1, 0)), Some(&EAST), EAST, true, |_| false),
+            Some(GridStep::Walk(destination)) if destination.0 == IVec3::new(1, 1, 0)
+        ));
+        for (start, direction) in [(IVec3::new(1, 1, 0), EAST), (IVec3::new(2, 1, 0), WEST)] {
+            assert!(matches!(
+                grid_step(
+                    &grid_world,
+                    &GridPosition(start),
+                    Some(&direction),
+                    direction,
+                    true,
+                    |_| false
+                ),
+                Some(GridStep::EdgeBlocked)
+            ));
+        }
+        assert_eq!(
+            path(
+                &grid_world,
+                IVec3::new(0, 1, 0),
+                EAST,
+                IVec3::new(2, 1, 0),
+                true
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn long_drop_beats_walking_down() {
+        let mut world = World::new();
+        // a ledge three levels up that drops straight onto the goal
+        solid(&mut world, (0..4).map(|z| IVec3::new(0, 0, z)));
+        solid(&mut world, [IVec3::new(0, -3, 4)]);
+        // and a staircase of ramps beside it that gets there two steps slower
+        solid(&mut world, [IVec3::new(1, 0, 0), IVec3::new(1, -3, 4)]);
+        for step in 1..4 {
+            let ramp = IVec3::new(1, 1 - step, step);
+            solid(&mut world, [ramp - IVec3::Y]);
+            world.spawn((
+                GridPosition(ramp),
+                GridDirection(Direction::North),
+                RampBlockMarker,
+            ));
+        }
+        let grid_world = GridWorld::from_world(&mut world);
+
+        assert_eq!(
+            path(
+                &grid_world,
+                IVec3::new(1, 1, 0),
+                SOUTH,
+                IVec3::new(1, -2, 4),
+                true
+            ),
+            Some(vec![SOUTH; 4])
+        );
+        assert_eq!(
+            path(
+                &grid_world,
+                IVec3::new(0, 1, 0),
+                SOUTH,
+                IVec3::new(0, -2, 4),
+                true
+            ),
+            Some(vec![SOUTH; 4])
+        );
+    }
+}
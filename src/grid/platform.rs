@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+
+use crate::{animation::GridAnimated, config::GRID_PLATFORM_STEP_INTERVAL};
+
+use super::{
+    movement::GridPositionMove,
+    push::GridPushable,
+    turn::{GridClock, GridTurnActor},
+    GridPosition, GridSystemSet, GridWorld,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(grid_platform_setup_observer)
+        .add_systems(Update, grid_platform_system.in_set(GridSystemSet::Movement));
+}
+
+#[derive(Component, Clone, Debug)]
+#[require(GridTurnActor)]
+pub struct GridPlatform {
+    path: Vec<IVec3>,
+    origin: IVec3,
+    next: usize,
+    timer: Timer,
+}
+
+impl GridPlatform {
+    pub fn new(path: Vec<IVec3>) -> Self {
+        Self {
+            path,
+            origin: IVec3::ZERO,
+            next: 0,
+            timer: Timer::new(GRID_PLATFORM_STEP_INTERVAL, TimerMode::Repeating),
+        }
+    }
+
+    pub fn path(&self) -> &[IVec3] {
+        &self.path
+    }
+
+    fn step(&mut self, position: &GridPosition) -> Option<IVec3> {
+        if self.path.len() < 2 {
+            return None;
+        }
+
+        if self.origin + self.path[self.next] == position.0 {
+            self.next = (self.next + 1) % self.path.len();
+        }
+
+        let delta = self.origin + self.path[self.next] - position.0;
+
+        // one axis per step so every cell along the way gets a collision check
+        let step = if delta.y != 0 {
+            IVec3::Y * delta.y.signum()
+        } else if delta.x != 0 {
+            IVec3::X * delta.x.signum()
+        } else {
+            IVec3::Z * delta.z.signum()
+        };
+
+        Some(step)
+    }
+}
+
+fn grid_platform_setup_observer(
+    trigger: Trigger<OnInsert, GridPlatform>,
+    mut platforms: Query<(&GridPosition, &mut GridPlatform)>,
+) {
+    if let Ok((position, mut platform)) = platforms.get_mut(trigger.entity()) {
+        platform.origin = position.0;
+    }
+}
+
+fn grid_platform_system(
+    mut commands: Commands,
+    mut platforms: Query<(Entity, &GridPosition, &mut GridPlatform)>,
+    carriable: Query<(), With<GridAnimated>>,
+    movers: Query<(), Or<(With<GridAnimated>, With<GridPushable>)>>,
+    grid_world: Res<GridWorld>,
+    clock: GridClock,
+) {
+    for (entity, position, mut platform) in &mut platforms {
+        if !clock.ready(entity, &mut platform.timer) {
+            continue;
+        }
+
+        let Some(step) = platform.step(position) else {
+            continue;
+        };
+
+        let riders = grid_world
+            .occupants(&position.up())
+            .map(|occupant| occupant.entity)
+            .filter(|&rider| rider != entity && carriable.contains(rider))
+            .collect::<Vec<_>>();
+
+        // movers like the player don't collide, but a platform still mustn't move into them
+        let obstructed = |cell: GridPosition| {
+            grid_world.occupants(&cell).any(|occupant| {
+                (occupant.collides || movers.contains(occupant.entity))
+                    && occupant.entity != entity
+                    && !riders.contains(&occupant.entity)
+            })
+        };
+
+        let destination = GridPosition(position.0 + step);
+        let rider_destination = GridPosition(position.up().0 + step);
+
+        if obstructed(destination) || (!riders.is_empty() && obstructed(rider_destination)) {
+            continue;
+        }
+
+        commands
+            .entity(entity)
+            .trigger(GridPositionMove(destination));
+
+        for rider in riders {
+            commands
+                .entity(rider)
+                .trigger(GridPositionMove(rider_destination));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{ecs::system::RunSystemOnce, prelude::*};
+
+    use crate::{
+        animation::GridAnimated,
+        config::GRID_PLATFORM_STEP_INTERVAL,
+        grid::{
+            movement::GridPositionMove,
+            turn::{TimeMode, TurnScheduler},
+            GridPosition, GridWorld,
+        },
+    };
+
+    use super::{grid_platform_system, GridPlatform};
+
+    #[derive(Resource, Default, Deref, DerefMut)]
+    struct Moved(Vec<Entity>);
+
+    // steps a platform at the origin one cell along +x, with `blocker` standing in its way
+    fn step_platform(blocker: Option<impl Bundle>) -> Vec<Entity> {
+        let mut world = World::new();
+        world.init_resource::<Moved>();
+        world.init_resource::<TimeMode>();
+        world.init_resource::<TurnScheduler>();
+        world.add_observer(
+            |trigger: Trigger<GridPositionMove>, mut moved: ResMut<Moved>| {
+                moved.push(trigger.entity());
+            },
+        );
+
+        let mut time = Time::<()>::default();
+        time.advance_by(GRID_PLATFORM_STEP_INTERVAL);
+        world.insert_resource(time);
+
+        world.spawn((
+            GridPosition(IVec3::ZERO),
+            GridPlatform::new(vec![IVec3::ZERO, IVec3::X]),
+        ));
+        if let Some(blocker) = blocker {
+            world.spawn((GridPosition(IVec3::X), blocker));
+        }
+
+        let grid_world = GridWorld::from_world(&mut world);
+        world.insert_resource(grid_world);
+        world.run_system_once(grid_platform_system).unwrap();
+
+        world.remove_resource::<Moved>().unwrap().0
+    }
+
+    #[test]
+    fn platform_moves_into_empty_cell() {
+        assert_eq!(step_platform(None::<()>).len(), 1);
+    }
+
+    #[test]
+    fn platform_is_blocked_by_standing_player() {
+        assert!(step_platform(Some(GridAnimated::default())).is_empty());
+    }
+}
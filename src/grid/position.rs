@@ -52,28 +52,54 @@ impl GridPosition {
         Self(**self + IVec3::new(0, -1, 0))
     }
 
+    fn slope_edge_offset(
+        edge_direction: GridDirection,
+        slope_direction: GridDirection,
+    ) -> (i32, i32) {
+        let edge_direction_index = Self::direction_vector_offset(&edge_direction) as i32;
+        let slope_direction_index = Self::direction_vector_offset(&slope_direction) as i32;
+
+        match (edge_direction_index - slope_direction_index).rem_euclid(4) {
+            0 => (1, 1),
+            3 => (0, 1),
+            2 => (0, 0),
+            1 => (1, 0),
+            _ => unreachable!(),
+        }
+    }
+
     pub fn edge_heights(
         &self,
         edge_direction: GridDirection,
         ramp_direction: Option<GridDirection>,
     ) -> (i32, i32) {
         if let Some(ramp_direction) = ramp_direction {
-            let edge_direction_index = Self::direction_vector_offset(&edge_direction) as i32;
-            let ramp_direction_index = Self::direction_vector_offset(&ramp_direction) as i32;
-
-            let offset = match (edge_direction_index - ramp_direction_index).rem_euclid(4) {
-                0 => (1, 1),
-                3 => (0, 1),
-                2 => (0, 0),
-                1 => (1, 0),
-                _ => unreachable!(),
-            };
+            let offset = Self::slope_edge_offset(edge_direction, ramp_direction);
 
             (self.y + offset.0, self.y + offset.1)
         } else {
             (self.y, self.y)
         }
     }
+
+    // heights of the underside of whatever fills the cell overhead, at this cell's edge. a solid
+    // block bottoms out at the top of this cell, while a flipped ramp's underside lies in the same
+    // plane as the top of a floor ramp facing the same way, so it opens a full cell higher there
+    pub fn ceiling_edge_heights(
+        &self,
+        edge_direction: GridDirection,
+        flipped_ramp_direction: Option<GridDirection>,
+    ) -> (i32, i32) {
+        let ceiling = self.y + 1;
+
+        if let Some(flipped_ramp_direction) = flipped_ramp_direction {
+            let offset = Self::slope_edge_offset(edge_direction, flipped_ramp_direction);
+
+            (ceiling + offset.0, ceiling + offset.1)
+        } else {
+            (ceiling, ceiling)
+        }
+    }
 }
 
 impl From<GridPosition> for Vec3 {
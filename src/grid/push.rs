@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use super::{movement::step_destination, GridDirection, GridPosition, GridWorld};
+
+#[derive(Component, Clone, Debug, Default)]
+pub struct GridPushable;
+
+pub fn push_destination(
+    grid_world: &GridWorld,
+    is_pushable: impl Fn(Entity) -> bool,
+    mover_position: &GridPosition,
+    direction: GridDirection,
+) -> Option<(Entity, GridPosition, GridPosition)> {
+    let ahead = mover_position.forward(&direction);
+
+    let pushable = grid_world
+        .occupants(&ahead)
+        .map(|occupant| occupant.entity)
+        .find(|&entity| is_pushable(entity))?;
+
+    let pushable_destination = step_destination(grid_world, &ahead, direction, None)?;
+
+    // the mover follows into the cell the pushable leaves, so it is ignored as a collider there
+    let destination = step_destination(grid_world, mover_position, direction, Some(pushable))
+        .filter(|destination| destination.0 == ahead.0)?;
+
+    Some((pushable, pushable_destination, destination))
+}
@@ -0,0 +1,123 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use bevy_tween::bevy_time_runner::TimeRunner;
+
+use crate::{
+    animation::{
+        GridClimbTween, GridFallTween, GridMoveBlockedTween, GridMoveTween, GridMoveTweenHolder,
+        GridWarpTween,
+    },
+    config::{GRID_MOVE_BLEND_FRACTION, GRID_MOVE_QUEUE_BLEND, GRID_MOVE_QUEUE_DEPTH},
+};
+
+use super::{
+    movement::{GridDirectionMove, GridPositionMoveAttempt},
+    turn::TurnScheduler,
+    GridDirection, GridSystemSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        Update,
+        grid_move_queue_system
+            .in_set(GridSystemSet::HandleInput)
+            .after(super::input::handle_grid_movement_input_system),
+    );
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GridMoveInput {
+    Forward,
+    Right,
+    Back,
+    Left,
+    TurnLeft,
+    TurnRight,
+}
+
+#[derive(Component, Debug)]
+pub struct GridMoveQueue {
+    pub depth: usize,
+    pub blend: bool,
+    inputs: VecDeque<GridMoveInput>,
+}
+
+impl Default for GridMoveQueue {
+    fn default() -> Self {
+        Self {
+            depth: GRID_MOVE_QUEUE_DEPTH,
+            blend: GRID_MOVE_QUEUE_BLEND,
+            inputs: VecDeque::new(),
+        }
+    }
+}
+
+impl GridMoveQueue {
+    pub fn push(&mut self, input: GridMoveInput) {
+        if self.inputs.len() < self.depth.max(1) {
+            self.inputs.push_back(input);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inputs.is_empty()
+    }
+}
+
+pub(super) type GridMoving = Or<(
+    With<GridMoveTween>,
+    With<GridMoveBlockedTween>,
+    With<GridFallTween>,
+    With<GridClimbTween>,
+    With<GridWarpTween>,
+)>;
+
+// the next input waits for the running movement tween, which removes itself once it finishes
+fn grid_move_queue_system(
+    mut commands: Commands,
+    mut queues: Query<(Entity, &GridDirection, &mut GridMoveQueue)>,
+    moving: Query<(), GridMoving>,
+    move_runners: Query<(&Parent, &TimeRunner), With<GridMoveTweenHolder>>,
+    turns: Res<TurnScheduler>,
+) {
+    for (entity, grid_direction, mut queue) in &mut queues {
+        let blending = queue.blend
+            && move_runners.iter().any(|(parent, runner)| {
+                parent.get() == entity
+                    && runner.elapsed().now()
+                        >= runner.length().as_secs_f32() * GRID_MOVE_BLEND_FRACTION
+            });
+
+        if (moving.contains(entity) && !blending) || turns.busy() {
+            continue;
+        }
+
+        let Some(input) = queue.inputs.pop_front() else {
+            continue;
+        };
+
+        let mut entity = commands.entity(entity);
+
+        match input {
+            GridMoveInput::Forward => {
+                entity.trigger(GridPositionMoveAttempt(*grid_direction));
+            }
+            GridMoveInput::Right => {
+                entity.trigger(GridPositionMoveAttempt(grid_direction.right()));
+            }
+            GridMoveInput::Back => {
+                entity.trigger(GridPositionMoveAttempt(grid_direction.reverse()));
+            }
+            GridMoveInput::Left => {
+                entity.trigger(GridPositionMoveAttempt(grid_direction.left()));
+            }
+            GridMoveInput::TurnLeft => {
+                entity.trigger(GridDirectionMove(grid_direction.left()));
+            }
+            GridMoveInput::TurnRight => {
+                entity.trigger(GridDirectionMove(grid_direction.right()));
+            }
+        }
+    }
+}
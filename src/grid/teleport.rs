@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+
+use super::{
+    movement::{GridMoveStyle, GridPositionMove},
+    GridPosition, GridWorld,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(grid_teleporter_enter_observer)
+        .add_observer(grid_teleport_observer);
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct GridTeleporter {
+    pub destination: Entity,
+}
+
+// only movers marked as teleportable warp, so pushed blocks and platform riders stay put
+#[derive(Component, Debug, Clone)]
+pub struct GridTeleportable;
+
+#[derive(Event, Debug, Clone)]
+pub struct GridTeleport {
+    pub teleporter: GridPosition,
+    pub destination: GridPosition,
+}
+
+fn grid_teleporter_enter_observer(
+    trigger: Trigger<GridPositionMove>,
+    mut commands: Commands,
+    teleporters: Query<&GridTeleporter>,
+    teleportable: Query<(), With<GridTeleportable>>,
+    positions: Query<&GridPosition>,
+    grid_world: Res<GridWorld>,
+) {
+    if !teleportable.contains(trigger.entity()) {
+        return;
+    }
+
+    let Some(destination) = grid_world
+        .occupants(&trigger.0)
+        .find_map(|occupant| teleporters.get(occupant.entity).ok())
+        .and_then(|teleporter| positions.get(teleporter.destination).ok())
+    else {
+        return;
+    };
+
+    commands.entity(trigger.entity()).trigger(GridTeleport {
+        teleporter: trigger.0,
+        destination: *destination,
+    });
+}
+
+// arriving is not a GridPositionMove, so landing on another teleporter doesn't bounce straight back
+fn grid_teleport_observer(
+    trigger: Trigger<GridTeleport>,
+    mut commands: Commands,
+    mut grid_position: Query<&mut GridPosition>,
+    mut grid_world: ResMut<GridWorld>,
+) {
+    if let Ok(mut grid_position) = grid_position.get_mut(trigger.entity()) {
+        *grid_position = trigger.destination;
+        grid_world.relocate(trigger.entity(), trigger.destination);
+
+        commands
+            .entity(trigger.entity())
+            .insert(GridMoveStyle::Warp {
+                teleporter: trigger.teleporter,
+            });
+    }
+}
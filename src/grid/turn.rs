@@ -0,0 +1,153 @@
+use std::collections::VecDeque;
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_rand::prelude::{Entropy, WyRand};
+use rand::SeedableRng;
+
+use crate::{
+    camera::PlayerCamera,
+    config::{TIME_MODE, TURN_RNG_SEED},
+};
+
+use super::{
+    movement::{GridDirectionMove, GridPositionMove},
+    queue::GridMoving,
+    GridSystemSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.insert_resource(TIME_MODE)
+        .init_resource::<TurnScheduler>()
+        .init_resource::<GridRng>()
+        .add_observer(turn_position_observer)
+        .add_observer(turn_direction_observer)
+        .add_systems(
+            Update,
+            (
+                time_mode_input_system.in_set(GridSystemSet::HandleInput),
+                turn_rng_seed_system.run_if(resource_changed::<TimeMode>),
+            )
+                .chain(),
+        )
+        .add_systems(
+            PostUpdate,
+            turn_advance_system.in_set(GridSystemSet::Cleanup),
+        );
+}
+
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeMode {
+    #[default]
+    RealTime,
+    TurnBased,
+}
+
+impl TimeMode {
+    pub fn toggle(&mut self) {
+        *self = match self {
+            TimeMode::RealTime => TimeMode::TurnBased,
+            TimeMode::TurnBased => TimeMode::RealTime,
+        };
+    }
+}
+
+// anything that acts on the clock, like npcs and platforms
+#[derive(Component, Clone, Debug, Default)]
+pub struct GridTurnActor;
+
+// shared by everything that acts on the clock, and reseeded whenever turns start so the same
+// moves always play out the same way
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct GridRng(Entropy<WyRand>);
+
+#[derive(Resource, Clone, Debug, Default)]
+pub struct TurnScheduler {
+    pending: bool,
+    actors: VecDeque<Entity>,
+    acting: Option<Entity>,
+    settling: bool,
+}
+
+impl TurnScheduler {
+    pub fn busy(&self) -> bool {
+        self.pending || self.settling
+    }
+}
+
+#[derive(SystemParam)]
+pub struct GridClock<'w> {
+    mode: Res<'w, TimeMode>,
+    turns: Res<'w, TurnScheduler>,
+    time: Res<'w, Time>,
+}
+
+impl GridClock<'_> {
+    pub fn ready(&self, actor: Entity, timer: &mut Timer) -> bool {
+        match *self.mode {
+            TimeMode::RealTime => timer.tick(self.time.delta()).just_finished(),
+            TimeMode::TurnBased => self.turns.acting == Some(actor),
+        }
+    }
+}
+
+// moves made while a turn resolves (like being carried by a platform) don't start another one
+fn queue_turn(mode: &TimeMode, turns: &mut TurnScheduler) {
+    if *mode == TimeMode::TurnBased && !turns.busy() {
+        turns.pending = true;
+    }
+}
+
+fn turn_position_observer(
+    trigger: Trigger<GridPositionMove>,
+    player: Query<(), With<PlayerCamera>>,
+    mode: Res<TimeMode>,
+    mut turns: ResMut<TurnScheduler>,
+) {
+    if player.contains(trigger.entity()) {
+        queue_turn(&mode, &mut turns);
+    }
+}
+
+fn turn_direction_observer(
+    trigger: Trigger<GridDirectionMove>,
+    player: Query<(), With<PlayerCamera>>,
+    mode: Res<TimeMode>,
+    mut turns: ResMut<TurnScheduler>,
+) {
+    if player.contains(trigger.entity()) {
+        queue_turn(&mode, &mut turns);
+    }
+}
+
+fn time_mode_input_system(input: Res<ButtonInput<KeyCode>>, mut time_mode: ResMut<TimeMode>) {
+    if input.just_pressed(KeyCode::KeyT) {
+        time_mode.toggle();
+    }
+}
+
+fn turn_rng_seed_system(mode: Res<TimeMode>, mut rng: ResMut<GridRng>) {
+    if *mode == TimeMode::TurnBased {
+        rng.0 = Entropy::seed_from_u64(TURN_RNG_SEED);
+    }
+}
+
+// actors take their turns one per frame in entity order, so each one sees where the others before
+// it ended up. input is accepted again once the last of them has stopped moving
+fn turn_advance_system(
+    mut turns: ResMut<TurnScheduler>,
+    actors: Query<Entity, With<GridTurnActor>>,
+    moving: Query<(), (With<GridTurnActor>, GridMoving)>,
+) {
+    if turns.pending {
+        let mut actors = actors.iter().collect::<Vec<_>>();
+        actors.sort();
+
+        turns.actors = actors.into();
+        turns.pending = false;
+        turns.settling = true;
+    } else if turns.acting.is_none() && turns.actors.is_empty() && moving.is_empty() {
+        turns.settling = false;
+    }
+
+    turns.acting = turns.actors.pop_front();
+}
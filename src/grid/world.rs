@@ -0,0 +1,201 @@
+use bevy::{prelude::*, utils::HashMap};
+
+use crate::blocks::{LadderBlockMarker, RampBlockMarker, RampFlippedBlockMarker};
+
+use super::{
+    edge::EdgeCollider,
+    movement::{GridCollides, GridHalfHeight},
+    GridDirection, GridPosition, GridSystemSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<GridWorld>()
+        .add_observer(grid_world_insert_observer)
+        .add_observer(grid_world_replace_observer)
+        .add_systems(
+            Update,
+            grid_world_changed_system.in_set(GridSystemSet::Movement),
+        );
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GridOccupant {
+    pub entity: Entity,
+    pub collides: bool,
+    pub half_height: bool,
+    pub edge_collider: Option<(EdgeCollider, GridDirection)>,
+    pub ramp: Option<GridDirection>,
+    pub flipped_ramp: Option<GridDirection>,
+    pub ladder: Option<GridDirection>,
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct GridWorld {
+    cells: HashMap<IVec3, Vec<GridOccupant>>,
+    locations: HashMap<Entity, IVec3>,
+}
+
+impl GridWorld {
+    pub fn occupants(&self, position: &GridPosition) -> impl Iterator<Item = &GridOccupant> {
+        self.cells.get(&position.0).into_iter().flatten()
+    }
+
+    pub fn collider(&self, position: &GridPosition) -> Option<Entity> {
+        self.occupants(position)
+            .find(|occupant| occupant.collides)
+            .map(|occupant| occupant.entity)
+    }
+
+    pub fn has_collider(&self, position: &GridPosition) -> bool {
+        self.collider(position).is_some()
+    }
+
+    pub fn ramp(&self, position: &GridPosition) -> Option<GridDirection> {
+        self.occupants(position).find_map(|occupant| occupant.ramp)
+    }
+
+    pub fn flipped_ramp(&self, position: &GridPosition) -> Option<GridDirection> {
+        self.occupants(position)
+            .find_map(|occupant| occupant.flipped_ramp)
+    }
+
+    pub fn ladder(&self, position: &GridPosition) -> Option<GridDirection> {
+        self.occupants(position)
+            .find_map(|occupant| occupant.ladder)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = (GridPosition, &[GridOccupant])> {
+        self.cells
+            .iter()
+            .map(|(&position, occupants)| (GridPosition(position), occupants.as_slice()))
+    }
+
+    // indexes everything already positioned, for tools that spawn blocks without the grid plugin
+    pub fn from_world(world: &mut World) -> Self {
+        let mut grid_world = Self::default();
+        let mut positioned = world.query_filtered::<EntityRef, With<GridPosition>>();
+
+        for (position, occupant) in positioned.iter(world).filter_map(grid_occupant) {
+            grid_world.insert(position, occupant);
+        }
+
+        grid_world
+    }
+
+    pub fn relocate(&mut self, entity: Entity, position: GridPosition) {
+        let Some(occupant) = self
+            .locations
+            .get(&entity)
+            .filter(|&&location| location != position.0)
+            .and_then(|location| self.cells.get(location))
+            .and_then(|cell| cell.iter().find(|occupant| occupant.entity == entity))
+            .copied()
+        else {
+            return;
+        };
+
+        self.insert(position, occupant);
+    }
+
+    fn insert(&mut self, position: GridPosition, occupant: GridOccupant) {
+        self.remove(occupant.entity);
+        self.locations.insert(occupant.entity, position.0);
+        self.cells.entry(position.0).or_default().push(occupant);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        let Some(location) = self.locations.remove(&entity) else {
+            return;
+        };
+
+        if let Some(cell) = self.cells.get_mut(&location) {
+            cell.retain(|occupant| occupant.entity != entity);
+            if cell.is_empty() {
+                self.cells.remove(&location);
+            }
+        }
+    }
+}
+
+fn grid_occupant(entity_ref: EntityRef) -> Option<(GridPosition, GridOccupant)> {
+    let position = *entity_ref.get::<GridPosition>()?;
+    let direction = entity_ref.get::<GridDirection>().copied();
+    let ramp = direction.filter(|_| entity_ref.contains::<RampBlockMarker>());
+    let flipped_ramp = direction.filter(|_| entity_ref.contains::<RampFlippedBlockMarker>());
+    let ladder = direction.filter(|_| entity_ref.contains::<LadderBlockMarker>());
+
+    Some((
+        position,
+        GridOccupant {
+            entity: entity_ref.id(),
+            collides: entity_ref.contains::<GridCollides>(),
+            half_height: entity_ref.contains::<GridHalfHeight>(),
+            edge_collider: entity_ref
+                .get::<EdgeCollider>()
+                .map(|&collider| (collider, direction.unwrap_or_default())),
+            ramp,
+            flipped_ramp,
+            ladder,
+        },
+    ))
+}
+
+fn refresh_grid_world(entity: Entity) -> impl FnOnce(&mut World) {
+    move |world: &mut World| {
+        let indexed = world.get_entity(entity).ok().and_then(grid_occupant);
+
+        let mut grid_world = world.resource_mut::<GridWorld>();
+        match indexed {
+            Some((position, occupant)) => grid_world.insert(position, occupant),
+            None => grid_world.remove(entity),
+        }
+    }
+}
+
+fn grid_world_insert_observer(
+    trigger: Trigger<
+        OnInsert,
+        (
+            GridPosition,
+            GridDirection,
+            GridCollides,
+            GridHalfHeight,
+            EdgeCollider,
+            RampBlockMarker,
+            RampFlippedBlockMarker,
+            LadderBlockMarker,
+        ),
+    >,
+    mut commands: Commands,
+) {
+    commands.queue(refresh_grid_world(trigger.entity()));
+}
+
+fn grid_world_replace_observer(
+    trigger: Trigger<
+        OnReplace,
+        (
+            GridPosition,
+            GridDirection,
+            GridCollides,
+            GridHalfHeight,
+            EdgeCollider,
+            RampBlockMarker,
+            RampFlippedBlockMarker,
+            LadderBlockMarker,
+        ),
+    >,
+    mut commands: Commands,
+) {
+    // queued so the refresh sees the entity after the removal or despawn has applied
+    commands.queue(refresh_grid_world(trigger.entity()));
+}
+
+fn grid_world_changed_system(
+    mut grid_world: ResMut<GridWorld>,
+    changed: Query<(Entity, &GridPosition), Changed<GridPosition>>,
+) {
+    for (entity, &position) in &changed {
+        grid_world.relocate(entity, position);
+    }
+}
@@ -0,0 +1,222 @@
+use std::{error::Error, time::Duration};
+
+use bevy::{
+    asset::{io::Reader, ron, AssetLoader, LoadContext},
+    gltf::Gltf,
+    prelude::*,
+    render::view::RenderLayers,
+};
+use serde::Deserialize;
+
+use crate::{
+    animation::ItemBobTween,
+    camera::{PlayerAssets, PlayerCamera},
+    combat::SwordState,
+    GameStates,
+};
+
+pub(crate) fn plugin(app: &mut App) {
+    app.init_asset::<HeldItems>()
+        .init_asset_loader::<HeldItemsLoader>()
+        .init_resource::<HeldItemSelection>()
+        .add_systems(
+            Update,
+            held_item_input_system.run_if(in_state(GameStates::Playing)),
+        )
+        .add_observer(player_held_item_observer)
+        .add_observer(held_item_cycle_observer);
+}
+
+// the items the player can hold, in cycling order, from `player.items.ron`
+#[derive(Asset, TypePath, Deserialize, Deref, Debug)]
+pub struct HeldItems(Vec<HeldItem>);
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct HeldItem {
+    pub id: String,
+    // file path of the model within the `player.models` collection in assets.ron
+    pub model: String,
+    pub scale: Vec3,
+    // local euler angles, applied x then y then z
+    pub rotation: Vec3,
+    pub bob_start: Vec3,
+    pub bob_end: Vec3,
+    // seconds
+    pub bob_duration: f32,
+    #[serde(default)]
+    pub melee: bool,
+}
+
+#[derive(Default)]
+struct HeldItemsLoader;
+
+impl AssetLoader for HeldItemsLoader {
+    type Asset = HeldItems;
+    type Settings = ();
+    type Error = Box<dyn Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<HeldItems, Self::Error> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["items.ron"]
+    }
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct HeldItemSelection(usize);
+
+#[derive(Component, Clone, Debug)]
+pub struct EquippedItem {
+    pub id: String,
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct HeldItemCycle;
+
+fn held_item_input_system(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    player: Query<Entity, With<PlayerCamera>>,
+) {
+    let Ok(player) = player.get_single() else {
+        return;
+    };
+
+    if input.just_pressed(KeyCode::KeyR) {
+        commands.trigger_targets(HeldItemCycle, player);
+    }
+}
+
+fn player_held_item_observer(
+    trigger: Trigger<OnAdd, PlayerCamera>,
+    commands: Commands,
+    mut selection: ResMut<HeldItemSelection>,
+    handles: Res<PlayerAssets>,
+    assets_held_items: Res<Assets<HeldItems>>,
+    assets_gltf: Res<Assets<Gltf>>,
+) {
+    let Some(items) = assets_held_items.get(&handles.held_items) else {
+        return;
+    };
+
+    for item in items.iter() {
+        if model(item, &handles, &assets_gltf).is_none() {
+            log::warn!("held item {} has no loaded model {}", item.id, item.model);
+        }
+    }
+
+    // the selection carries over a respawn, but falls through to the next item with a model
+    let Some(index) = (0..items.len())
+        .map(|offset| (selection.0 + offset) % items.len())
+        .find(|&index| model(&items[index], &handles, &assets_gltf).is_some())
+    else {
+        return;
+    };
+
+    selection.0 = index;
+
+    spawn_held_item(
+        commands,
+        trigger.entity(),
+        &items[index],
+        &handles,
+        &assets_gltf,
+    );
+}
+
+fn held_item_cycle_observer(
+    trigger: Trigger<HeldItemCycle>,
+    mut commands: Commands,
+    mut selection: ResMut<HeldItemSelection>,
+    equipped: Query<(Entity, &Parent), With<EquippedItem>>,
+    handles: Res<PlayerAssets>,
+    assets_held_items: Res<Assets<HeldItems>>,
+    assets_gltf: Res<Assets<Gltf>>,
+) {
+    let Some(items) = assets_held_items.get(&handles.held_items) else {
+        return;
+    };
+
+    // items without a loaded model are skipped, and the current one stays if there's nothing else
+    let Some(next) = (1..=items.len())
+        .map(|offset| (selection.0 + offset) % items.len())
+        .find(|&index| model(&items[index], &handles, &assets_gltf).is_some())
+        .filter(|&index| index != selection.0)
+    else {
+        return;
+    };
+
+    selection.0 = next;
+
+    for (entity, parent) in &equipped {
+        if parent.get() == trigger.entity() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    spawn_held_item(
+        commands,
+        trigger.entity(),
+        &items[next],
+        &handles,
+        &assets_gltf,
+    );
+}
+
+fn model<'a>(
+    item: &HeldItem,
+    handles: &PlayerAssets,
+    assets_gltf: &'a Assets<Gltf>,
+) -> Option<&'a Gltf> {
+    handles
+        .models
+        .get(&item.model)
+        .and_then(|handle| assets_gltf.get(handle))
+}
+
+fn spawn_held_item(
+    mut commands: Commands,
+    player: Entity,
+    item: &HeldItem,
+    handles: &PlayerAssets,
+    assets_gltf: &Assets<Gltf>,
+) {
+    let Some(gltf) = model(item, handles, assets_gltf) else {
+        return;
+    };
+
+    let mut transform = Transform::from_translation(item.bob_start).with_scale(item.scale);
+    transform.rotate_local_x(item.rotation.x);
+    transform.rotate_local_y(item.rotation.y);
+    transform.rotate_local_z(item.rotation.z);
+
+    let mut entity = commands.spawn((
+        RenderLayers::layer(0), // setting this does not set gltf children
+        SceneRoot(gltf.scenes[0].clone()),
+        ItemBobTween {
+            start: item.bob_start,
+            end: item.bob_end,
+            duration: Duration::from_secs_f32(item.bob_duration),
+        },
+        transform,
+        EquippedItem {
+            id: item.id.clone(),
+        },
+    ));
+
+    if item.melee {
+        entity.insert(SwordState::default());
+    }
+
+    entity.set_parent(player);
+}
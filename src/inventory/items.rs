@@ -0,0 +1,164 @@
+use bevy::prelude::*;
+use bevy_persistent::{Persistent, StorageFormat};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    camera::{PersistClearEvent, PersistEvent, PlayerCamera},
+    Flags, GameStates,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(Startup, initialize_inventory_system)
+        .add_systems(
+            Update,
+            inventory_input_system.run_if(in_state(GameStates::Playing)),
+        )
+        .add_observer(item_use_observer)
+        .add_observer(persist_inventory_observer)
+        .add_observer(clear_inventory_observer);
+}
+
+#[derive(Resource, Serialize, Deserialize, Clone, Debug, Default)]
+pub struct Inventory {
+    #[serde(default)]
+    pub items: Vec<ItemStack>,
+    // iids of pickups already taken, so reloading the level doesn't hand them out again
+    #[serde(default)]
+    pub collected: Vec<String>,
+    // iids of doors the player has unlocked, which stay unlocked across reloads
+    #[serde(default)]
+    pub unlocked: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ItemStack {
+    pub id: String,
+    pub count: u32,
+    pub consumable: bool,
+}
+
+impl Inventory {
+    pub fn count(&self, id: &str) -> u32 {
+        self.items
+            .iter()
+            .find(|stack| stack.id == id)
+            .map_or(0, |stack| stack.count)
+    }
+
+    pub fn add(&mut self, id: &str, count: u32, consumable: bool) {
+        match self.items.iter_mut().find(|stack| stack.id == id) {
+            Some(stack) => stack.count += count,
+            None => self.items.push(ItemStack {
+                id: id.to_string(),
+                count,
+                consumable,
+            }),
+        }
+    }
+
+    pub fn remove(&mut self, id: &str, count: u32) -> bool {
+        let Some(index) = self.items.iter().position(|stack| stack.id == id) else {
+            return false;
+        };
+
+        if self.items[index].count < count {
+            return false;
+        }
+
+        self.items[index].count -= count;
+        if self.items[index].count == 0 {
+            self.items.remove(index);
+        }
+
+        true
+    }
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct ItemUse(pub usize);
+
+#[derive(Event, Debug, Clone)]
+pub struct ItemConsumed(pub String);
+
+fn initialize_inventory_system(mut commands: Commands) {
+    commands.insert_resource(
+        Persistent::<Inventory>::builder()
+            .name("inventory")
+            .format(StorageFormat::Toml)
+            .path("inventory.toml")
+            .revertible(true)
+            .default(Inventory::default())
+            .build()
+            .expect("failed to initialize persistent inventory"),
+    );
+}
+
+fn inventory_input_system(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    flags: Res<Flags>,
+    player: Query<Entity, With<PlayerCamera>>,
+) {
+    if !flags.inventory {
+        return;
+    }
+
+    let Ok(player) = player.get_single() else {
+        return;
+    };
+
+    for press in input.get_just_pressed() {
+        let slot = match press {
+            KeyCode::Digit1 => 0,
+            KeyCode::Digit2 => 1,
+            KeyCode::Digit3 => 2,
+            KeyCode::Digit4 => 3,
+            KeyCode::Digit5 => 4,
+            KeyCode::Digit6 => 5,
+            KeyCode::Digit7 => 6,
+            KeyCode::Digit8 => 7,
+            KeyCode::Digit9 => 8,
+            _ => continue,
+        };
+
+        commands.trigger_targets(ItemUse(slot), player);
+    }
+}
+
+fn item_use_observer(
+    trigger: Trigger<ItemUse>,
+    mut commands: Commands,
+    mut inventory: ResMut<Persistent<Inventory>>,
+) {
+    let Some(id) = inventory
+        .items
+        .get(trigger.0)
+        .filter(|stack| stack.consumable)
+        .map(|stack| stack.id.clone())
+    else {
+        return;
+    };
+
+    inventory.remove(&id, 1);
+
+    commands.trigger_targets(ItemConsumed(id), trigger.entity());
+}
+
+// only saved alongside the player's position, so a restart never mixes the two
+fn persist_inventory_observer(
+    _trigger: Trigger<PersistEvent>,
+    inventory: Res<Persistent<Inventory>>,
+) {
+    inventory
+        .persist()
+        .expect("failed to persist inventory to inventory.toml");
+}
+
+fn clear_inventory_observer(
+    _trigger: Trigger<PersistClearEvent>,
+    mut inventory: ResMut<Persistent<Inventory>>,
+) {
+    inventory
+        .revert_to_default()
+        .expect("failed to clear persistent inventory.");
+}
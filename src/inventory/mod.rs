@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+
+mod items;
+mod pickup;
+
+pub use items::{Inventory, ItemConsumed};
+pub use pickup::Pickup;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins((items::plugin, pickup::plugin));
+}
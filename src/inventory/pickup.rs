@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use bevy_persistent::Persistent;
+
+use crate::{
+    blocks::LdtkIid,
+    camera::PlayerCamera,
+    grid::{GridPosition, GridSystemSet, GridWorld},
+};
+
+use super::Inventory;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_observer(collected_pickup_observer)
+        .add_systems(Update, pickup_system.after(GridSystemSet::Movement));
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct Pickup {
+    pub id: String,
+    pub count: u32,
+    pub consumable: bool,
+}
+
+fn collected_pickup_observer(
+    trigger: Trigger<OnAdd, Pickup>,
+    mut commands: Commands,
+    pickups: Query<&LdtkIid, With<Pickup>>,
+    inventory: Res<Persistent<Inventory>>,
+) {
+    if pickups
+        .get(trigger.entity())
+        .is_ok_and(|iid| inventory.collected.contains(&iid.0))
+    {
+        commands.entity(trigger.entity()).despawn_recursive();
+    }
+}
+
+fn pickup_system(
+    mut commands: Commands,
+    player: Query<&GridPosition, (With<PlayerCamera>, Changed<GridPosition>)>,
+    pickups: Query<(&Pickup, Option<&LdtkIid>)>,
+    grid_world: Res<GridWorld>,
+    mut inventory: ResMut<Persistent<Inventory>>,
+) {
+    let Ok(position) = player.get_single() else {
+        return;
+    };
+
+    for occupant in grid_world.occupants(position) {
+        let Ok((pickup, iid)) = pickups.get(occupant.entity) else {
+            continue;
+        };
+
+        inventory.add(&pickup.id, pickup.count, pickup.consumable);
+        if let Some(iid) = iid {
+            inventory.collected.push(iid.0.clone());
+        }
+
+        commands.entity(occupant.entity).despawn_recursive();
+    }
+}
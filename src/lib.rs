@@ -11,9 +11,14 @@ use bevy_tween::DefaultTweenPlugins;
 mod animation;
 mod blocks;
 mod camera;
+mod combat;
 mod config;
 mod grid;
+mod held_item;
+mod inventory;
+pub mod lint;
 mod loading;
+mod message;
 mod sound;
 mod widgets;
 
@@ -30,6 +35,8 @@ pub struct Cube;
 pub struct Flags {
     debug: bool,
     sound: bool,
+    inventory: bool,
+    report: bool,
 }
 
 #[derive(Default, States, Clone, Debug, Hash, Eq, PartialEq)]
@@ -37,6 +44,7 @@ pub enum GameStates {
     #[default]
     Loading,
     Playing,
+    Dead,
 }
 
 pub fn plugin(app: &mut App) {
@@ -65,11 +73,17 @@ pub fn plugin(app: &mut App) {
         FrameTimeDiagnosticsPlugin,
         EntityCountDiagnosticsPlugin,
         EntropyPlugin::<WyRand>::default(),
+    ))
+    .add_plugins((
         animation::plugin,
         blocks::plugin,
         camera::plugin,
+        combat::plugin,
         grid::plugin,
+        held_item::plugin,
+        inventory::plugin,
         loading::plugin,
+        message::plugin,
         sound::plugin,
         #[cfg(not(feature = "egui"))]
         terminal::plugin,
@@ -80,6 +94,8 @@ pub fn plugin(app: &mut App) {
     .insert_resource(Flags {
         debug: false,
         sound: false,
+        inventory: false,
+        report: false,
     })
     .insert_resource(ClearColor(Color::BLACK))
     .add_systems(Update, global_input_system);
@@ -101,6 +117,12 @@ pub fn global_input_system(
             KeyCode::KeyM => {
                 flags.sound = !flags.sound;
             }
+            KeyCode::KeyI => {
+                flags.inventory = !flags.inventory;
+            }
+            KeyCode::KeyL => {
+                flags.report = !flags.report;
+            }
             _ => {}
         }
     }
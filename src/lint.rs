@@ -0,0 +1,276 @@
+use std::{fmt, fs, path::Path, process::ExitCode};
+
+use bevy::{
+    ecs::system::SystemState,
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+use bevy_ecs_ldtk::{
+    ldtk::{loaded_level::LoadedLevel, LdtkJson},
+    prelude::RawLevelAccessor,
+};
+
+use crate::{
+    blocks::{self, spawn_level, BlockRegistry, LdtkIid, TeleporterDestinationIid},
+    config::PLAYER_STARTING_POSITION,
+    grid::{
+        step_destination, Direction, GridCollides, GridDirection, GridPlatform, GridPosition,
+        GridWorld, Interactable,
+    },
+    inventory::Pickup,
+};
+
+static DEFAULT_LEVEL_PATH: &str = "assets/level.ldtk";
+
+enum Finding {
+    Error(String),
+    Warning(String),
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Finding::Error(message) => write!(f, "error: {message}"),
+            Finding::Warning(message) => write!(f, "warning: {message}"),
+        }
+    }
+}
+
+struct Cell(IVec3);
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}, {}", self.0.x, self.0.y, self.0.z)
+    }
+}
+
+// loads a level file through the same block registry as the game, without any rendering
+pub fn run() -> ExitCode {
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| DEFAULT_LEVEL_PATH.to_string());
+
+    let mut app = match load(Path::new(&path)) {
+        Ok(app) => app,
+        Err(message) => {
+            eprintln!("error: {path}: {message}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let (findings, levels) = lint(app.world_mut());
+
+    for finding in &findings {
+        println!("{finding}");
+    }
+
+    let errors = findings
+        .iter()
+        .filter(|finding| matches!(finding, Finding::Error(_)))
+        .count();
+    let warnings = findings.len() - errors;
+
+    println!("{path}: {levels} levels, {errors} errors, {warnings} warnings");
+
+    if errors > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn load(path: &Path) -> Result<App, String> {
+    let json = fs::read_to_string(path).map_err(|error| error.to_string())?;
+    let ldtk: LdtkJson = serde_json::from_str(&json).map_err(|error| error.to_string())?;
+
+    let tileset_path = ldtk
+        .defs
+        .tilesets
+        .first()
+        .and_then(|tileset| tileset.rel_path.as_ref())
+        .ok_or("no tileset defined")?;
+    let tileset = image::open(path.parent().unwrap_or(Path::new(".")).join(tileset_path))
+        .map_err(|error| format!("{tileset_path}: {error}"))?;
+
+    let mut app = App::new();
+    app.add_plugins(blocks::register_blocks)
+        .init_resource::<Assets<Mesh>>()
+        .init_resource::<LevelCells>()
+        .init_resource::<LintDiagnostics>();
+
+    let world = app.world_mut();
+    let mut state = SystemState::<(Commands, ResMut<Assets<Mesh>>, Res<BlockRegistry>)>::new(world);
+    let mut positioned = world.query::<(Entity, &GridPosition)>();
+    let mut seen = HashSet::default();
+
+    for raw_level in ldtk.iter_raw_levels() {
+        let Ok(level) = LoadedLevel::try_from(raw_level) else {
+            return Err(format!(
+                "level {} is stored externally, which is not supported",
+                raw_level.identifier
+            ));
+        };
+
+        let (commands, mut meshes, registry) = state.get_mut(world);
+        let diagnostics = spawn_level(
+            &level,
+            &registry,
+            commands,
+            &mut meshes,
+            &tileset,
+            &Handle::default(),
+        );
+        state.apply(world);
+
+        // anything positioned that wasn't there before belongs to this level
+        let cells = positioned
+            .iter(world)
+            .filter(|(entity, _)| seen.insert(*entity))
+            .map(|(_, position)| position.0)
+            .collect();
+
+        world
+            .resource_mut::<LevelCells>()
+            .push((level.identifier().clone(), cells));
+        world
+            .resource_mut::<LintDiagnostics>()
+            .extend(diagnostics.iter().map(ToString::to_string));
+    }
+
+    Ok(app)
+}
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct LevelCells(Vec<(String, HashSet<IVec3>)>);
+
+#[derive(Resource, Default, Deref, DerefMut)]
+struct LintDiagnostics(Vec<String>);
+
+fn lint(world: &mut World) -> (Vec<Finding>, usize) {
+    let mut findings = world
+        .resource::<LintDiagnostics>()
+        .iter()
+        .cloned()
+        .map(Finding::Error)
+        .collect::<Vec<_>>();
+
+    let grid_world = GridWorld::from_world(world);
+
+    for (position, occupants) in grid_world.cells() {
+        if occupants
+            .iter()
+            .filter(|occupant| occupant.collides)
+            .count()
+            > 1
+        {
+            findings.push(Finding::Error(format!(
+                "overlapping colliders at {}",
+                Cell(position.0)
+            )));
+        }
+
+        for direction in occupants.iter().filter_map(|occupant| occupant.ramp) {
+            for (end, facing) in [("top", direction), ("bottom", direction.reverse())] {
+                if step_destination(&grid_world, &position, facing, None).is_none() {
+                    findings.push(Finding::Error(format!(
+                        "ramp at {} has no landing at its {end}",
+                        Cell(position.0)
+                    )));
+                }
+            }
+        }
+    }
+
+    findings.extend(unreachable(world));
+
+    (findings, world.resource::<LevelCells>().len())
+}
+
+fn unreachable(world: &mut World) -> Vec<Finding> {
+    // closed doors and the like can be opened, so only solid scenery counts when walking the level
+    let interactables = world
+        .query_filtered::<Entity, (With<Interactable>, With<GridCollides>)>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    for entity in interactables {
+        world.entity_mut(entity).remove::<GridCollides>();
+    }
+
+    let grid_world = GridWorld::from_world(world);
+    let mut links = HashMap::<IVec3, Vec<IVec3>>::default();
+
+    let iids = world
+        .query::<(&LdtkIid, &GridPosition)>()
+        .iter(world)
+        .map(|(iid, position)| (iid.to_string(), position.0))
+        .collect::<HashMap<_, _>>();
+    for (destination_iid, position) in world
+        .query::<(&TeleporterDestinationIid, &GridPosition)>()
+        .iter(world)
+    {
+        if let Some(&destination) = iids.get(destination_iid.as_str()) {
+            links.entry(position.0).or_default().push(destination);
+        }
+    }
+
+    // a rider can get on or off at any stop along the path
+    for (platform, position) in world.query::<(&GridPlatform, &GridPosition)>().iter(world) {
+        let stops = platform
+            .path()
+            .iter()
+            .map(|&offset| position.up().0 + offset)
+            .collect::<Vec<_>>();
+
+        for &stop in &stops {
+            links.entry(stop).or_default().extend(&stops);
+        }
+    }
+
+    let start = GridPosition(PLAYER_STARTING_POSITION);
+    let reached = grid_world.reachable(&start, &links);
+
+    let mut findings = vec![];
+
+    for (level, cells) in world.resource::<LevelCells>().iter() {
+        if !reached
+            .iter()
+            .any(|cell| cells.contains(&(*cell - IVec3::Y)))
+        {
+            findings.push(Finding::Warning(format!(
+                "level {level} is unreachable from {}",
+                Cell(start.0)
+            )));
+        }
+    }
+
+    // interacting happens from a neighbouring cell, so content only needs one reachable side
+    let is_reached = |position: &GridPosition| {
+        reached.contains(&position.0)
+            || Direction::ALL
+                .map(GridDirection)
+                .iter()
+                .any(|direction| reached.contains(&position.forward(direction).0))
+    };
+
+    for (position, interactable) in world.query::<(&GridPosition, &Interactable)>().iter(world) {
+        if !is_reached(position) {
+            findings.push(Finding::Warning(format!(
+                "unreachable \"{}\" at {}",
+                interactable.prompt,
+                Cell(position.0)
+            )));
+        }
+    }
+
+    for (position, pickup) in world.query::<(&GridPosition, &Pickup)>().iter(world) {
+        if !is_reached(position) {
+            findings.push(Finding::Warning(format!(
+                "unreachable {} pickup at {}",
+                pickup.id,
+                Cell(position.0)
+            )));
+        }
+    }
+
+    findings
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::config::MESSAGE_DURATION;
+
+pub(crate) fn plugin(app: &mut App) {
+    app.init_resource::<StatusMessage>()
+        .add_observer(show_message_observer)
+        .add_systems(Update, status_message_system);
+}
+
+#[derive(Event, Debug, Clone)]
+pub struct ShowMessage(pub String);
+
+#[derive(Resource, Default, Debug)]
+pub struct StatusMessage(Option<(String, Timer)>);
+
+impl StatusMessage {
+    pub fn text(&self) -> Option<&str> {
+        self.0.as_ref().map(|(text, _)| text.as_str())
+    }
+}
+
+fn show_message_observer(trigger: Trigger<ShowMessage>, mut message: ResMut<StatusMessage>) {
+    message.0 = Some((
+        trigger.0.clone(),
+        Timer::new(MESSAGE_DURATION, TimerMode::Once),
+    ));
+}
+
+fn status_message_system(mut message: ResMut<StatusMessage>, time: Res<Time>) {
+    if let Some((_, timer)) = message.0.as_mut() {
+        if timer.tick(time.delta()).finished() {
+            message.0 = None;
+        }
+    }
+}
@@ -6,13 +6,16 @@ use bevy_rand::prelude::{Entropy, WyRand};
 use rand::seq::SliceRandom;
 
 use crate::{
-    grid::{GridDirectionMove, GridPositionMoveAttempt},
+    combat::SwordSwing,
+    grid::{GridDirectionMove, GridFall, GridPositionMoveAttempt},
     Flags,
 };
 
 pub(super) fn plugin(app: &mut App) {
     app.add_observer(move_position_sfx_observer)
-        .add_observer(move_direction_sfx_observer);
+        .add_observer(move_direction_sfx_observer)
+        .add_observer(fall_sfx_observer)
+        .add_observer(swing_sfx_observer);
 }
 
 #[derive(AssetCollection, Resource)]
@@ -23,8 +26,10 @@ pub struct SfxAssets {
     gravel_sfx: Vec<Handle<AudioSource>>,
     #[asset(key = "sfx.snow", collection(typed))]
     snow_sfx: Vec<Handle<AudioSource>>,
+    #[asset(key = "sfx.swing", collection(typed))]
+    swing_sfx: Vec<Handle<AudioSource>>,
     #[asset(key = "sfx.wood", collection(typed))]
-    _wood_sfx: Vec<Handle<AudioSource>>,
+    wood_sfx: Vec<Handle<AudioSource>>,
 }
 
 fn move_position_sfx_observer(
@@ -67,6 +72,46 @@ fn move_direction_sfx_observer(
     play_sfx(commands, trigger.entity(), sfx);
 }
 
+fn fall_sfx_observer(
+    trigger: Trigger<GridFall>,
+    flags: Res<Flags>,
+    commands: Commands,
+    sfx_assets: Res<SfxAssets>,
+    mut rng: Local<Entropy<WyRand>>,
+) {
+    if !flags.sound {
+        return;
+    }
+
+    let sfx = sfx_assets
+        .wood_sfx
+        .choose(&mut rng.deref_mut())
+        .unwrap()
+        .clone();
+
+    play_sfx(commands, trigger.entity(), sfx);
+}
+
+fn swing_sfx_observer(
+    trigger: Trigger<SwordSwing>,
+    flags: Res<Flags>,
+    commands: Commands,
+    sfx_assets: Res<SfxAssets>,
+    mut rng: Local<Entropy<WyRand>>,
+) {
+    if !flags.sound {
+        return;
+    }
+
+    let sfx = sfx_assets
+        .swing_sfx
+        .choose(&mut rng.deref_mut())
+        .unwrap()
+        .clone();
+
+    play_sfx(commands, trigger.entity(), sfx);
+}
+
 fn play_sfx(mut commands: Commands, entity: Entity, sfx: Handle<AudioSource>) {
     let mut entity = commands.entity(entity);
     entity.remove::<AudioSink>();
@@ -9,7 +9,7 @@ use bevy::log::tracing_subscriber;
 use bevy::log::tracing_subscriber::layer::SubscriberExt;
 use bevy::log::tracing_subscriber::util::SubscriberInitExt;
 use bevy::prelude::*;
-use bevy::utils::error;
+use bevy::utils::{error, HashMap};
 use bevy_persistent::Persistent;
 use bevy_ratatui::kitty::KittyEnabled;
 use bevy_ratatui::terminal::RatatuiContext;
@@ -17,9 +17,19 @@ use bevy_ratatui::RatatuiPlugins;
 use bevy_ratatui_camera::RatatuiCameraWidget;
 use crossterm::event::KeyEventKind;
 
+use crate::blocks::LevelReport;
 use crate::camera::{BackgroundCamera, PlayerCamera, PlayerPersist, WorldCamera};
-use crate::grid::{GridDirection, GridPosition};
-use crate::widgets::debug_frame::debug_frame;
+use crate::combat::{DamageFlash, Health};
+use crate::config::{
+    DAMAGE_FLASH_STRENGTH, TERMINAL_KEY_REPEAT_DELAY, TERMINAL_KEY_REPEAT_TIMEOUT,
+};
+use crate::grid::{FacedInteractable, GridDirection, GridPosition, Interactable, TimeMode};
+use crate::inventory::Inventory;
+use crate::message::StatusMessage;
+use crate::widgets::damage_flash::damage_flash;
+use crate::widgets::debug_frame::{debug_frame, PlayerStatus};
+use crate::widgets::inventory_panel::inventory_panel;
+use crate::widgets::level_report::level_report;
 use crate::Flags;
 use crate::GameStates;
 
@@ -34,13 +44,14 @@ pub(super) fn plugin(app: &mut App) {
         ScheduleRunnerPlugin::run_loop(Duration::from_secs_f32(1. / 90.)),
         RatatuiPlugins::default(),
     ))
+    .init_resource::<TerminalKeyRepeat>()
     .add_systems(
         Update,
         (
             draw_scene_system.map(error),
             temporary_terminal_forward_system,
         )
-            .run_if(in_state(GameStates::Playing)),
+            .run_if(not(in_state(GameStates::Loading))),
     );
 }
 
@@ -56,7 +67,19 @@ fn draw_scene_system(
     diagnostics: Res<DiagnosticsStore>,
     kitty_enabled: Option<Res<KittyEnabled>>,
     persist: Res<Persistent<PlayerPersist>>,
+    faced: Res<FacedInteractable>,
+    interactables: Query<&Interactable>,
+    time_mode: Res<TimeMode>,
+    health: Query<&Health, With<PlayerCamera>>,
+    flash: Res<DamageFlash>,
+    inventory: Res<Persistent<Inventory>>,
+    message: Res<StatusMessage>,
+    report: Res<LevelReport>,
 ) -> io::Result<()> {
+    let interact_prompt = faced
+        .and_then(|entity| interactables.get(entity).ok())
+        .map(|interactable| interactable.prompt.as_str());
+
     ratatui.draw(|frame| {
         let area = debug_frame(
             frame,
@@ -65,6 +88,12 @@ fn draw_scene_system(
             kitty_enabled.as_deref(),
             player.get_single().ok(),
             &persist,
+            PlayerStatus {
+                interact_prompt,
+                message: message.text(),
+                time_mode: *time_mode,
+                health: health.get_single().ok(),
+            },
             true,
         );
 
@@ -77,60 +106,149 @@ fn draw_scene_system(
         if let Ok(w) = player_widget.get_single() {
             w.render_autoresize(area, frame.buffer_mut(), &mut commands);
         }
+
+        if flash.intensity() > 0. {
+            damage_flash(
+                frame.buffer_mut(),
+                area,
+                flash.intensity() * DAMAGE_FLASH_STRENGTH,
+            );
+        }
+
+        if flags.inventory {
+            inventory_panel(frame, area, &inventory);
+        }
+
+        if flags.report && !report.is_empty() {
+            level_report(frame, area, &report);
+        }
     })?;
 
     Ok(())
 }
 
+#[derive(Debug)]
+struct TerminalKey {
+    character: char,
+    since_press: f32,
+    held: bool,
+}
+
+#[derive(Resource, Default, Deref, DerefMut, Debug)]
+struct TerminalKeyRepeat(HashMap<KeyCode, TerminalKey>);
+
 //TODO: fix input forwarding in bevy_ratatui
 pub fn temporary_terminal_forward_system(
     mut commands: Commands,
     mut ratatui_input: EventReader<bevy_ratatui::event::KeyEvent>,
     mut bevy_input: EventWriter<KeyboardInput>,
+    mut repeats: ResMut<TerminalKeyRepeat>,
     window: Query<Entity, With<Window>>,
     dummy_window: Query<Entity, With<DummyWindow>>,
+    kitty_enabled: Option<Res<KittyEnabled>>,
+    time: Res<Time>,
 ) {
     let window_entity = window
         .get_single()
         .or(dummy_window.get_single())
         .unwrap_or_else(|_| commands.spawn(DummyWindow).id());
 
+    let mut send_key = |character: char, key_code, state| {
+        bevy_input.send(KeyboardInput {
+            key_code,
+            logical_key: Key::Character(character.to_string().into()),
+            state,
+            repeat: false,
+            window: window_entity,
+        });
+    };
+
+    for key in repeats.values_mut() {
+        key.since_press += time.delta_secs();
+    }
+
     for bevy_ratatui::event::KeyEvent(kc) in ratatui_input.read() {
-        if let KeyEventKind::Release = kc.kind {
+        let Some((character, key_code)) = forwarded_key(kc.code) else {
+            continue;
+        };
+
+        // with the kitty protocol, releases arrive as their own events so held keys stay down.
+        if kitty_enabled.is_some() {
+            match kc.kind {
+                KeyEventKind::Press => send_key(character, key_code, ButtonState::Pressed),
+                KeyEventKind::Release => send_key(character, key_code, ButtonState::Released),
+                _ => {}
+            }
             continue;
         }
 
-        let mut send_key = |character: char, bevy_keycode| {
-            bevy_input.send(KeyboardInput {
-                key_code: bevy_keycode,
-                logical_key: Key::Character(character.to_string().into()),
-                state: ButtonState::Pressed,
-                repeat: false,
-                window: window_entity,
-            });
-            bevy_input.send(KeyboardInput {
-                key_code: bevy_keycode,
-                logical_key: Key::Character(character.to_string().into()),
-                state: ButtonState::Released,
-                repeat: false,
-                window: window_entity,
-            });
-        };
+        if kc.kind != KeyEventKind::Press {
+            continue;
+        }
 
-        match kc.code {
-            crossterm::event::KeyCode::Char('w') => send_key('w', KeyCode::KeyW),
-            crossterm::event::KeyCode::Char('d') => send_key('d', KeyCode::KeyD),
-            crossterm::event::KeyCode::Char('s') => send_key('s', KeyCode::KeyS),
-            crossterm::event::KeyCode::Char('a') => send_key('a', KeyCode::KeyA),
-            crossterm::event::KeyCode::Char('q') => send_key('q', KeyCode::KeyQ),
-            crossterm::event::KeyCode::Char('e') => send_key('e', KeyCode::KeyE),
-            crossterm::event::KeyCode::Char('m') => send_key('m', KeyCode::KeyM),
-            crossterm::event::KeyCode::Char('p') => send_key('p', KeyCode::KeyP),
-            crossterm::event::KeyCode::Char('o') => send_key('o', KeyCode::KeyO),
-            crossterm::event::KeyCode::Tab => send_key('t', KeyCode::Tab),
-            crossterm::event::KeyCode::Esc => send_key('x', KeyCode::Escape),
-            _ => {}
-        };
+        // otherwise a lone press is a tap, and the terminal's key repeat turns it into a hold
+        match repeats.get_mut(&key_code) {
+            Some(key) if key.since_press < TERMINAL_KEY_REPEAT_DELAY => {
+                if !key.held {
+                    send_key(character, key_code, ButtonState::Pressed);
+                    key.held = true;
+                }
+                key.since_press = 0.;
+            }
+            _ => {
+                send_key(character, key_code, ButtonState::Pressed);
+                send_key(character, key_code, ButtonState::Released);
+                repeats.insert(
+                    key_code,
+                    TerminalKey {
+                        character,
+                        since_press: 0.,
+                        held: false,
+                    },
+                );
+            }
+        }
+    }
+
+    repeats.retain(|&key_code, key| {
+        if key.held && key.since_press > TERMINAL_KEY_REPEAT_TIMEOUT {
+            send_key(key.character, key_code, ButtonState::Released);
+            return false;
+        }
+
+        key.held || key.since_press < TERMINAL_KEY_REPEAT_DELAY
+    });
+}
+
+fn forwarded_key(code: crossterm::event::KeyCode) -> Option<(char, KeyCode)> {
+    match code {
+        crossterm::event::KeyCode::Char('w') => Some(('w', KeyCode::KeyW)),
+        crossterm::event::KeyCode::Char('d') => Some(('d', KeyCode::KeyD)),
+        crossterm::event::KeyCode::Char('s') => Some(('s', KeyCode::KeyS)),
+        crossterm::event::KeyCode::Char('a') => Some(('a', KeyCode::KeyA)),
+        crossterm::event::KeyCode::Char('q') => Some(('q', KeyCode::KeyQ)),
+        crossterm::event::KeyCode::Char('e') => Some(('e', KeyCode::KeyE)),
+        crossterm::event::KeyCode::Char('f') => Some(('f', KeyCode::KeyF)),
+        crossterm::event::KeyCode::Char('r') => Some(('r', KeyCode::KeyR)),
+        crossterm::event::KeyCode::Char('t') => Some(('t', KeyCode::KeyT)),
+        crossterm::event::KeyCode::Char(' ') => Some((' ', KeyCode::Space)),
+        crossterm::event::KeyCode::Char('m') => Some(('m', KeyCode::KeyM)),
+        crossterm::event::KeyCode::Char('i') => Some(('i', KeyCode::KeyI)),
+        crossterm::event::KeyCode::Char('l') => Some(('l', KeyCode::KeyL)),
+        crossterm::event::KeyCode::Char('1') => Some(('1', KeyCode::Digit1)),
+        crossterm::event::KeyCode::Char('2') => Some(('2', KeyCode::Digit2)),
+        crossterm::event::KeyCode::Char('3') => Some(('3', KeyCode::Digit3)),
+        crossterm::event::KeyCode::Char('4') => Some(('4', KeyCode::Digit4)),
+        crossterm::event::KeyCode::Char('5') => Some(('5', KeyCode::Digit5)),
+        crossterm::event::KeyCode::Char('6') => Some(('6', KeyCode::Digit6)),
+        crossterm::event::KeyCode::Char('7') => Some(('7', KeyCode::Digit7)),
+        crossterm::event::KeyCode::Char('8') => Some(('8', KeyCode::Digit8)),
+        crossterm::event::KeyCode::Char('9') => Some(('9', KeyCode::Digit9)),
+        crossterm::event::KeyCode::Char('p') => Some(('p', KeyCode::KeyP)),
+        crossterm::event::KeyCode::Char('o') => Some(('o', KeyCode::KeyO)),
+        crossterm::event::KeyCode::Tab => Some(('t', KeyCode::Tab)),
+        crossterm::event::KeyCode::Esc => Some(('x', KeyCode::Escape)),
+        _ => None,
     }
 }
 
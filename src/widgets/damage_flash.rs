@@ -0,0 +1,22 @@
+use ratatui::{buffer::Buffer, layout::Rect, style::Color};
+
+pub fn damage_flash(buffer: &mut Buffer, area: Rect, intensity: f32) {
+    for position in area.positions() {
+        if let Some(cell) = buffer.cell_mut(position) {
+            cell.fg = tint_red(cell.fg, intensity);
+            cell.bg = tint_red(cell.bg, intensity);
+        }
+    }
+}
+
+fn tint_red(color: Color, intensity: f32) -> Color {
+    let (r, g, b) = match color {
+        Color::Rgb(r, g, b) => (r, g, b),
+        _ => (0, 0, 0),
+    };
+
+    let mix =
+        |channel: u8, target: f32| (channel as f32 + (target - channel as f32) * intensity) as u8;
+
+    Color::Rgb(mix(r, 255.), mix(g, 0.), mix(b, 0.))
+}
@@ -14,11 +14,20 @@ use tui_logger::TuiLoggerWidget;
 
 use crate::{
     camera::PlayerPersist,
+    combat::Health,
     config::{PLAYER_STARTING_DIRECTION, PLAYER_STARTING_POSITION},
-    grid::{GridDirection, GridPosition},
+    grid::{GridDirection, GridPosition, TimeMode},
     Flags,
 };
 
+// what the status line shows about the player
+pub struct PlayerStatus<'a> {
+    pub interact_prompt: Option<&'a str>,
+    pub message: Option<&'a str>,
+    pub time_mode: TimeMode,
+    pub health: Option<&'a Health>,
+}
+
 pub fn debug_frame(
     frame: &mut Frame,
     flags: &Flags,
@@ -26,6 +35,7 @@ pub fn debug_frame(
     kitty_enabled: Option<&KittyEnabled>,
     player: Option<(&GridPosition, &GridDirection)>,
     persist: &Persistent<PlayerPersist>,
+    status: PlayerStatus,
     show_log_panel: bool,
 ) -> ratatui::layout::Rect {
     let main_block = Block::bordered()
@@ -45,7 +55,25 @@ pub fn debug_frame(
     let name_string = "ratthew";
     let name_line = Line::from(name_string).centered();
 
-    let mut settings_strings = vec![format!("sound: {}", if flags.sound { "ON" } else { "OFF" })];
+    let mut settings_strings = vec![
+        format!("sound: {}", if flags.sound { "ON" } else { "OFF" }),
+        format!(
+            "time: {}",
+            match status.time_mode {
+                TimeMode::RealTime => "REAL",
+                TimeMode::TurnBased => "TURNS",
+            }
+        ),
+    ];
+    if let Some(health) = status.health {
+        settings_strings.insert(0, format!("hp: {}/{}", health.current, health.max));
+    }
+    if let Some(prompt) = status.interact_prompt {
+        settings_strings.insert(0, format!("F: {prompt}"));
+    }
+    if let Some(message) = status.message {
+        settings_strings.insert(0, message.to_string());
+    }
     if !persist.position.eq(&PLAYER_STARTING_POSITION)
         || !persist.direction.0.eq(&PLAYER_STARTING_DIRECTION)
     {
@@ -63,6 +91,12 @@ pub fn debug_frame(
     let controls_string = [
         "WASD to move",
         "Q/E to turn",
+        "F to interact",
+        "SPACE to attack",
+        "R to swap item",
+        "T to toggle turns",
+        "I for inventory",
+        "L for level report",
         "M to toggle sound",
         "ESC to quit",
         "TAB to debug",
@@ -0,0 +1,34 @@
+use ratatui::{layout::Rect, style::Color, text::Line, Frame};
+
+use crate::inventory::Inventory;
+
+use super::panel::centered_panel;
+
+pub fn inventory_panel(frame: &mut Frame, area: Rect, inventory: &Inventory) {
+    let lines = if inventory.items.is_empty() {
+        vec![Line::from("empty")]
+    } else {
+        inventory
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, stack)| {
+                let slot = if index < 9 {
+                    format!("{}", index + 1)
+                } else {
+                    " ".to_string()
+                };
+                Line::from(format!("{slot}  {} x{}", stack.id, stack.count))
+            })
+            .collect::<Vec<_>>()
+    };
+
+    centered_panel(
+        frame,
+        area,
+        " inventory ",
+        " 1-9 to use ",
+        lines,
+        Color::Reset,
+    );
+}
@@ -0,0 +1,40 @@
+use ratatui::{layout::Rect, style::Color, text::Line, Frame};
+
+use crate::blocks::LevelReport;
+
+use super::panel::centered_panel;
+
+const MAX_LINES: usize = 8;
+
+pub fn level_report(frame: &mut Frame, area: Rect, report: &LevelReport) {
+    let mut lines = vec![Line::from(format!(
+        "{} block{} skipped while loading",
+        report.len(),
+        if report.len() == 1 { "" } else { "s" }
+    ))
+    .centered()];
+
+    lines.push(Line::from(""));
+    lines.extend(
+        report
+            .iter()
+            .take(MAX_LINES)
+            .map(|diagnostic| Line::from(diagnostic.to_string())),
+    );
+
+    if report.len() > MAX_LINES {
+        lines.push(Line::from(format!(
+            "... and {} more in the log",
+            report.len() - MAX_LINES
+        )));
+    }
+
+    centered_panel(
+        frame,
+        area,
+        " level report ",
+        " L to dismiss ",
+        lines,
+        Color::Yellow,
+    );
+}
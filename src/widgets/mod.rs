@@ -1 +1,5 @@
+pub mod damage_flash;
 pub mod debug_frame;
+pub mod inventory_panel;
+pub mod level_report;
+pub mod panel;
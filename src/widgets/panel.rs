@@ -0,0 +1,44 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Stylize},
+    text::Line,
+    widgets::{Block, Clear, Padding, Paragraph},
+    Frame,
+};
+
+// a bordered panel sized to fit its lines and hint, drawn over the middle of `area`
+pub fn centered_panel(
+    frame: &mut Frame,
+    area: Rect,
+    title: &str,
+    hint: &str,
+    lines: Vec<Line>,
+    fg: Color,
+) {
+    let width = lines
+        .iter()
+        .map(Line::width)
+        .chain([hint.len()])
+        .max()
+        .unwrap_or_default() as u16
+        + 8;
+    let height = lines.len() as u16 + 4;
+
+    let panel = Rect::new(
+        area.x + area.width.saturating_sub(width) / 2,
+        area.y + area.height.saturating_sub(height) / 2,
+        width,
+        height,
+    )
+    .intersection(area);
+
+    let block = Block::bordered()
+        .title(Line::from(title).centered())
+        .title_bottom(Line::from(hint).centered())
+        .padding(Padding::uniform(1))
+        .bg(Color::Black)
+        .fg(fg);
+
+    frame.render_widget(Clear, panel);
+    frame.render_widget(Paragraph::new(lines).block(block), panel);
+}